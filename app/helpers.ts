@@ -95,6 +95,10 @@ export async function getConfig() {
     name: "Consumer NFT",
     symbol: "CST",
     uri: tokenUri,
+    jurisdiction: {
+      countryCode: Array.from(Buffer.from("SG")),
+      investorClass: { professional: {} },
+    },
   };
   return {
     provider,
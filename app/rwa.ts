@@ -8,7 +8,6 @@ import {
   address,
   addSignersToTransactionMessage,
   appendTransactionMessageInstruction,
-  appendTransactionMessageInstructions,
   createTransactionMessage,
   generateKeyPairSigner,
   getAddressEncoder,
@@ -192,6 +191,14 @@ const do_test = async (
     console.info("Init token carbon credits mint");
     let { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
 
+    const [extraAccountMetaListAddress] = await getProgramDerivedAddress({
+      programAddress: fromLegacyPublicKey(transferHookProgram.programId),
+      seeds: [
+        Buffer.from("extra-account-metas"),
+        addressEncoder.encode(carbonCreditsMintAddress),
+      ],
+    });
+
     const initializeTokenMint = await program.methods
       .initRwaToken(
         "Carbon Credits",
@@ -201,24 +208,15 @@ const do_test = async (
         isClose,
         hasFee,
         feeBasisPoints ?? 0,
-        maxFee ?? new BN(0)
+        maxFee ?? new BN(0),
+        false,
+        false
       )
       .accounts({
-        transferHookProgram: transferHookProgram.programId,
+        // created by rwa-tokenization via CPI, the hook rejects any other initializer
+        extraAccountMetaList: isClose ? extraAccountMetaListAddress : null,
       })
       .instruction();
-    let initializeExtraAccountMetaListInstruction;
-    if (isClose) {
-      initializeExtraAccountMetaListInstruction =
-        await transferHookProgram.methods
-          .initializeExtraAccountMetaList()
-          .accounts({
-            payer: payer.address,
-            mint: carbonCreditsMintAddress,
-            rwaProgram: program.programId,
-          })
-          .instruction();
-    }
 
     const transactionMintNftMessage = pipe(
       createTransactionMessage({
@@ -227,20 +225,10 @@ const do_test = async (
       (tx) => setTransactionMessageFeePayer(admin.address, tx),
       (tx) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, tx),
       (tx) =>
-        !isClose
-          ? appendTransactionMessageInstruction(
-              fromLegacyTransactionInstruction(initializeTokenMint),
-              tx
-            )
-          : appendTransactionMessageInstructions(
-              [
-                fromLegacyTransactionInstruction(initializeTokenMint),
-                fromLegacyTransactionInstruction(
-                  initializeExtraAccountMetaListInstruction
-                ),
-              ],
-              tx
-            ),
+        appendTransactionMessageInstruction(
+          fromLegacyTransactionInstruction(initializeTokenMint),
+          tx
+        ),
       (tx) => addSignersToTransactionMessage([admin], tx)
     );
 
@@ -359,7 +347,8 @@ const do_test = async (
       .issueConsumerCert(
         consumernftMetadata.name,
         consumernftMetadata.symbol,
-        consumernftMetadata.uri
+        consumernftMetadata.uri,
+        consumernftMetadata.jurisdiction
       )
       .accounts({
         minter: minter.address,
@@ -405,12 +394,14 @@ const do_test = async (
     let { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
 
     const mintTokenInstruction = await program.methods
-      .mintRwaToken(new BN(300))
+      .mintRwaToken(new BN(300), null, false)
       .accounts({
         minter: minter.address,
         payer: admin.address,
         receiver: minter.address,
         rwaMint: carbonCreditsMintAddress,
+        lockup: null,
+        transferPolicy: null,
       })
       .instruction();
 
@@ -509,7 +500,8 @@ const do_test = async (
         .issueConsumerCert(
           consumernftMetadata.name,
           consumernftMetadata.symbol,
-          consumernftMetadata.uri
+          consumernftMetadata.uri,
+          consumernftMetadata.jurisdiction
         )
         .accounts({
           minter: minter.address,
@@ -606,15 +598,24 @@ const do_test = async (
         "Retire 5 carbon credits token from consumer and receive nft certificate"
       );
       let { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
-      const nftMint = await generateKeyPairSigner();
+      const retirementId = new BN(0);
+      const [nftMint] = await getProgramDerivedAddress({
+        programAddress: fromLegacyPublicKey(program.programId),
+        seeds: [
+          Buffer.from("rc"),
+          addressEncoder.encode(carbonCreditsMintAddress),
+          addressEncoder.encode(consumer1.address),
+          retirementId.toArrayLike(Buffer, "le", 8),
+        ],
+      });
 
       const instruction = await program.methods
-        .retireToken(new BN(5))
+        .retireToken(new BN(5), retirementId)
         .accounts({
           payer: admin.address,
           consumer: consumer1.address,
           mint: carbonCreditsMintAddress,
-          nftMint: nftMint.address,
+          nftMint,
         })
         .instruction();
 
@@ -630,7 +631,7 @@ const do_test = async (
             fromLegacyTransactionInstruction(instruction),
             tx
           ),
-        (tx) => addSignersToTransactionMessage([admin, consumer1, nftMint], tx)
+        (tx) => addSignersToTransactionMessage([admin, consumer1], tx)
       );
 
       const signedTransaction = await signTransactionMessageWithSigners(
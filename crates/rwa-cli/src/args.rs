@@ -0,0 +1,228 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Parser)]
+#[command(
+    name = "rwa-cli",
+    version,
+    about = "Operate the rwa-tokenization program"
+)]
+pub struct Cli {
+    /// JSON-RPC endpoint of the cluster
+    #[arg(long, global = true, default_value = "http://127.0.0.1:8899")]
+    pub url: String,
+    /// Keypair file of the fee payer, also the governance authority for admin commands
+    #[arg(long, global = true, default_value = "~/.config/solana/id.json")]
+    pub keypair: PathBuf,
+    /// Print the signed, serialized transaction instead of sending it
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create the governance config, the keypair becomes its authority
+    InitConfig,
+    /// Create an RWA token mint
+    CreateToken(CreateTokenArgs),
+    /// Issue a minter certificate nft
+    IssueMinter(IssueMinterArgs),
+    /// Set the available credits of a minter
+    SetQuota(SetQuotaArgs),
+    /// Issue a consumer certificate nft, signed by a minter
+    IssueConsumer(IssueConsumerArgs),
+    /// Mint RWA tokens against the minter credits
+    Mint(MintArgs),
+    /// Retire RWA tokens of a consumer for a retirement certificate
+    Retire(RetireArgs),
+    /// Show program accounts
+    #[command(subcommand)]
+    Show(ShowCommand),
+    /// Audit report of issued, retired and outstanding credits, reconciled against supply
+    Report(ReportArgs),
+    /// Check that a retirement certificate nft was issued by retire_token
+    VerifyCertificate(VerifyCertificateArgs),
+}
+
+#[derive(Args)]
+pub struct CreateTokenArgs {
+    #[arg(long)]
+    pub name: String,
+    #[arg(long)]
+    pub symbol: String,
+    #[arg(long, default_value_t = 0)]
+    pub decimals: u8,
+    #[arg(long, default_value = "")]
+    pub uri: String,
+    /// Guard transfers with the token-transfer-hook program
+    #[arg(long)]
+    pub transfer_hook: bool,
+    /// Enable the transfer fee extension with this rate
+    #[arg(long, requires = "maximum_fee")]
+    pub transfer_fee_basis_points: Option<u16>,
+    #[arg(long, requires = "transfer_fee_basis_points")]
+    pub maximum_fee: Option<u64>,
+    /// New token accounts start frozen until the consumer thaws them
+    #[arg(long)]
+    pub default_frozen: bool,
+    /// Make the mint authority permanent delegate for clawback
+    #[arg(long)]
+    pub clawback: bool,
+}
+
+#[derive(Args)]
+pub struct CertificateMetadataArgs {
+    #[arg(long)]
+    pub name: String,
+    #[arg(long)]
+    pub symbol: String,
+    #[arg(long, default_value = "")]
+    pub uri: String,
+}
+
+#[derive(Args)]
+pub struct IssueMinterArgs {
+    /// RWA token mint
+    #[arg(long)]
+    pub mint: Pubkey,
+    /// Wallet receiving the minter certificate
+    #[arg(long)]
+    pub minter: Pubkey,
+    #[command(flatten)]
+    pub metadata: CertificateMetadataArgs,
+}
+
+#[derive(Args)]
+pub struct SetQuotaArgs {
+    #[arg(long)]
+    pub mint: Pubkey,
+    #[arg(long)]
+    pub minter: Pubkey,
+    /// New available credits
+    #[arg(long)]
+    pub credits: u64,
+}
+
+#[derive(Args)]
+pub struct IssueConsumerArgs {
+    #[arg(long)]
+    pub mint: Pubkey,
+    /// Keypair file of the minter signing the issuance
+    #[arg(long)]
+    pub minter_keypair: PathBuf,
+    /// Wallet receiving the consumer certificate
+    #[arg(long)]
+    pub consumer: Pubkey,
+    /// ISO 3166-1 alpha-2 country code of the consumer
+    #[arg(long)]
+    pub country: String,
+    #[arg(long, value_enum)]
+    pub investor_class: InvestorClassArg,
+    #[command(flatten)]
+    pub metadata: CertificateMetadataArgs,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum InvestorClassArg {
+    Retail,
+    Professional,
+    Institutional,
+}
+
+#[derive(Args)]
+pub struct MintArgs {
+    #[arg(long)]
+    pub mint: Pubkey,
+    /// Keypair file of the minter
+    #[arg(long)]
+    pub minter_keypair: PathBuf,
+    /// Wallet receiving the tokens
+    #[arg(long)]
+    pub receiver: Pubkey,
+    /// Credits to mint, in whole tokens
+    #[arg(long)]
+    pub amount: u64,
+    /// Keep the minted tokens locked until this unix timestamp, the mint's transfer policy must
+    /// enforce lockups
+    #[arg(long)]
+    pub lockup_until: Option<i64>,
+    /// Release the locked tokens linearly until --lockup-until
+    #[arg(long, requires = "lockup_until")]
+    pub linear_vesting: bool,
+}
+
+#[derive(Args)]
+pub struct RetireArgs {
+    #[arg(long)]
+    pub mint: Pubkey,
+    /// Keypair file of the consumer retiring the tokens
+    #[arg(long)]
+    pub consumer_keypair: PathBuf,
+    /// Amount to retire, in base units
+    #[arg(long)]
+    pub amount: u64,
+    /// Id of the retirement, unused so far by this consumer; the certificate mint is derived
+    /// from it
+    #[arg(long)]
+    pub retirement_id: u64,
+}
+
+#[derive(Args)]
+pub struct ReportArgs {
+    /// RWA token mint to report on, repeat for several
+    #[arg(long = "mint", required = true)]
+    pub mints: Vec<Pubkey>,
+    /// JSON report of the previous period, issued and retired figures become changes since it
+    #[arg(long)]
+    pub previous: Option<PathBuf>,
+    /// Write each section as a CSV file into this directory
+    #[arg(long)]
+    pub csv_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct VerifyCertificateArgs {
+    /// Mint of the retirement certificate nft
+    #[arg(long)]
+    pub certificate: Pubkey,
+    /// RWA token mint the certificate must have been retired from
+    #[arg(long)]
+    pub mint: Pubkey,
+}
+
+#[derive(Subcommand)]
+pub enum ShowCommand {
+    /// Governance config
+    Config,
+    /// RWA token mint, its metadata and mint authority
+    Mint {
+        #[arg(long)]
+        mint: Pubkey,
+    },
+    /// Minter certificate and its credits
+    Minter {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        minter: Pubkey,
+    },
+    /// Consumer certificate and its jurisdiction
+    Consumer {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        consumer: Pubkey,
+    },
+}
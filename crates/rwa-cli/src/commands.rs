@@ -0,0 +1,241 @@
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rwa_tokenization_client::{
+    associated_token_address, config_pda, consumer_controller_pda, consumer_nft_pda, instructions,
+    lockup_pda, mint_authority_pda, minter_controller_pda, minter_nft_pda,
+    retirement_certificate_pda, rwa_mint_pda,
+    rwa_tokenization::instruction,
+    token_transfer_hook::{InvestorClass, Jurisdiction},
+};
+use serde_json::{json, Map, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+use crate::args::{
+    Cli, CreateTokenArgs, InvestorClassArg, IssueConsumerArgs, IssueMinterArgs, MintArgs,
+    RetireArgs, SetQuotaArgs,
+};
+
+pub struct Context {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+    pub dry_run: bool,
+}
+
+impl Context {
+    pub fn new(cli: &Cli) -> Result<Self> {
+        Ok(Self {
+            rpc: RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed()),
+            payer: load_keypair(&cli.keypair)?,
+            dry_run: cli.dry_run,
+        })
+    }
+
+    // signs with the payer and `signers`, then sends or, on dry run, only serializes
+    fn submit(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+        accounts: Value,
+    ) -> Result<Value> {
+        let blockhash = self
+            .rpc
+            .get_latest_blockhash()
+            .context("failed to fetch a recent blockhash")?;
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        let mut report = Map::new();
+        if self.dry_run {
+            let serialized = bincode::serialize(&transaction)?;
+            report.insert("transaction".into(), STANDARD.encode(serialized).into());
+        } else {
+            let signature = self
+                .rpc
+                .send_and_confirm_transaction(&transaction)
+                .context("transaction failed")?;
+            report.insert("signature".into(), signature.to_string().into());
+        }
+        report.insert("accounts".into(), accounts);
+        Ok(Value::Object(report))
+    }
+}
+
+fn load_keypair(path: &Path) -> Result<Keypair> {
+    let path = match path.strip_prefix("~") {
+        Ok(relative) => Path::new(&std::env::var("HOME")?).join(relative),
+        Err(_) => path.to_path_buf(),
+    };
+    read_keypair_file(&path).map_err(|err| anyhow!("failed to read keypair {path:?}: {err}"))
+}
+
+pub fn init_config(context: &Context) -> Result<Value> {
+    context.submit(
+        &[instructions::initialize(&context.payer.pubkey())],
+        &[],
+        json!({ "config": config_pda().to_string() }),
+    )
+}
+
+pub fn create_token(context: &Context, args: &CreateTokenArgs) -> Result<Value> {
+    let mint = rwa_mint_pda(&args.symbol);
+    let instruction = instructions::init_rwa_token(
+        &context.payer.pubkey(),
+        instruction::InitRwaToken {
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            decimals: args.decimals,
+            uri: args.uri.clone(),
+            is_close: args.transfer_hook,
+            has_fee: args.transfer_fee_basis_points.is_some(),
+            transfer_fee_basis_points: args.transfer_fee_basis_points,
+            maximum_fee: args.maximum_fee,
+            default_frozen: args.default_frozen,
+            has_clawback: args.clawback,
+        },
+    );
+    context.submit(
+        &[instruction],
+        &[],
+        json!({
+            "mint": mint.to_string(),
+            "mint_authority": mint_authority_pda(&mint).to_string(),
+        }),
+    )
+}
+
+pub fn issue_minter(context: &Context, args: &IssueMinterArgs) -> Result<Value> {
+    let nft_mint = minter_nft_pda(&args.mint, &args.minter);
+    let instruction = instructions::issue_minter_cert(
+        &context.payer.pubkey(),
+        &args.mint,
+        &args.minter,
+        instruction::IssueMinterCert {
+            name: args.metadata.name.clone(),
+            symbol: args.metadata.symbol.clone(),
+            uri: args.metadata.uri.clone(),
+        },
+    );
+    context.submit(
+        &[instruction],
+        &[],
+        json!({
+            "minter_nft_mint": nft_mint.to_string(),
+            "minter_controller": minter_controller_pda(&nft_mint).to_string(),
+        }),
+    )
+}
+
+pub fn set_quota(context: &Context, args: &SetQuotaArgs) -> Result<Value> {
+    let instruction = instructions::update_quota_credit(
+        &context.payer.pubkey(),
+        &args.mint,
+        &args.minter,
+        instruction::UpdateQuotaCredit {
+            new_credit: args.credits,
+        },
+    );
+    context.submit(
+        &[instruction],
+        &[],
+        json!({ "minter_nft_mint": minter_nft_pda(&args.mint, &args.minter).to_string() }),
+    )
+}
+
+pub fn issue_consumer(context: &Context, args: &IssueConsumerArgs) -> Result<Value> {
+    let minter = load_keypair(&args.minter_keypair)?;
+    let jurisdiction = Jurisdiction {
+        country_code: parse_country_code(&args.country)?,
+        investor_class: match args.investor_class {
+            InvestorClassArg::Retail => InvestorClass::Retail,
+            InvestorClassArg::Professional => InvestorClass::Professional,
+            InvestorClassArg::Institutional => InvestorClass::Institutional,
+        },
+    };
+    let nft_mint = consumer_nft_pda(&args.mint, &args.consumer);
+    let instruction = instructions::issue_consumer_cert(
+        &context.payer.pubkey(),
+        &minter.pubkey(),
+        &args.mint,
+        &args.consumer,
+        instruction::IssueConsumerCert {
+            name: args.metadata.name.clone(),
+            symbol: args.metadata.symbol.clone(),
+            uri: args.metadata.uri.clone(),
+            jurisdiction,
+        },
+    );
+    context.submit(
+        &[instruction],
+        &[&minter],
+        json!({
+            "consumer_nft_mint": nft_mint.to_string(),
+            "consumer_controller": consumer_controller_pda(&nft_mint).to_string(),
+        }),
+    )
+}
+
+fn parse_country_code(country: &str) -> Result<[u8; 2]> {
+    match country.as_bytes() {
+        [first, second] if country.bytes().all(|byte| byte.is_ascii_uppercase()) => {
+            Ok([*first, *second])
+        }
+        _ => bail!("country must be an uppercase ISO 3166-1 alpha-2 code, got {country:?}"),
+    }
+}
+
+pub fn mint(context: &Context, args: &MintArgs) -> Result<Value> {
+    let minter = load_keypair(&args.minter_keypair)?;
+    let instruction = instructions::mint_rwa_token(
+        &context.payer.pubkey(),
+        &minter.pubkey(),
+        &args.mint,
+        &args.receiver,
+        instruction::MintRwaToken {
+            amount: args.amount,
+            lockup_until: args.lockup_until,
+            linear_vesting: args.linear_vesting,
+        },
+    );
+    context.submit(
+        &[instruction],
+        &[&minter],
+        json!({
+            "receiver_token_account": associated_token_address(&args.receiver, &args.mint).to_string(),
+            "lockup": args.lockup_until.map(|_| lockup_pda(&args.mint, &args.receiver).to_string()),
+        }),
+    )
+}
+
+pub fn retire(context: &Context, args: &RetireArgs) -> Result<Value> {
+    let consumer = load_keypair(&args.consumer_keypair)?;
+    let instruction = instructions::retire_token(
+        &context.payer.pubkey(),
+        &consumer.pubkey(),
+        &args.mint,
+        instruction::RetireToken {
+            amount: args.amount,
+            retirement_id: args.retirement_id,
+        },
+    );
+    let certificate_mint =
+        retirement_certificate_pda(&args.mint, &consumer.pubkey(), args.retirement_id);
+    context.submit(
+        &[instruction],
+        &[&consumer],
+        json!({ "certificate_mint": certificate_mint.to_string() }),
+    )
+}
@@ -0,0 +1,37 @@
+mod args;
+mod commands;
+mod output;
+mod report;
+mod show;
+mod verify;
+
+use anyhow::Result;
+use clap::Parser;
+
+use crate::{
+    args::{Cli, Command},
+    commands::Context,
+};
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let context = Context::new(&cli)?;
+    let report = match &cli.command {
+        Command::InitConfig => commands::init_config(&context)?,
+        Command::CreateToken(args) => commands::create_token(&context, args)?,
+        Command::IssueMinter(args) => commands::issue_minter(&context, args)?,
+        Command::SetQuota(args) => commands::set_quota(&context, args)?,
+        Command::IssueConsumer(args) => commands::issue_consumer(&context, args)?,
+        Command::Mint(args) => commands::mint(&context, args)?,
+        Command::Retire(args) => commands::retire(&context, args)?,
+        Command::Show(command) => show::show(&context, command)?,
+        Command::Report(args) => report::report(&context, args)?,
+        Command::VerifyCertificate(args) => verify::verify_certificate(&context, args)?,
+    };
+    output::print(cli.output, &report);
+    // scripts checking certificates rely on the exit code
+    if report["genuine"] == false {
+        std::process::exit(1);
+    }
+    Ok(())
+}
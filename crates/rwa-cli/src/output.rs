@@ -0,0 +1,40 @@
+use serde_json::Value;
+
+use crate::args::OutputFormat;
+
+pub fn print(format: OutputFormat, report: &Value) {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(report).unwrap()),
+        OutputFormat::Text => print_text(report, 0),
+    }
+}
+
+fn print_text(value: &Value, indent: usize) {
+    let Value::Object(fields) = value else {
+        println!("{:indent$}{}", "", scalar(value));
+        return;
+    };
+    for (key, value) in fields {
+        match value {
+            Value::Object(_) => {
+                println!("{:indent$}{key}:", "");
+                print_text(value, indent + 2);
+            }
+            Value::Array(items) => {
+                println!("{:indent$}{key}:", "");
+                for item in items {
+                    println!("{:indent$}  - {}", "", scalar(item));
+                }
+            }
+            _ => println!("{:indent$}{key}: {}", "", scalar(value)),
+        }
+    }
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Null => "-".to_string(),
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,558 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use anchor_lang::Discriminator;
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        extension::StateWithExtensions,
+        state::{Account, Mint},
+    },
+};
+use anyhow::{Context as _, Result};
+use rwa_tokenization_client::{
+    compliance_action, credits, mint_authority_pda, minter_controller,
+    rwa_tokenization::{
+        self, ComplianceAction, ComplianceActionKind, MinterController, AVAILABLE_CREDITS_KEY,
+        MINTED_CREDITS_KEY,
+    },
+    token_metadata, verify_retirement_certificate, FetchedAccount,
+    RETIREMENT_CERT_AUTHORITY_OFFSET,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    account::Account as RpcAccount,
+    pubkey::Pubkey,
+    sysvar::clock::{self, Clock},
+};
+
+use crate::{args::ReportArgs, commands::Context};
+
+// minted, retired and outstanding figures of every mint at one slot. With a previous report
+// the period columns hold the change since it, otherwise everything since the mint was created.
+#[derive(Serialize, Deserialize)]
+pub struct Report {
+    pub slot: u64,
+    pub timestamp: i64,
+    pub period_start_slot: Option<u64>,
+    pub issued_per_minter: Vec<IssuedRow>,
+    pub retired_per_beneficiary: Vec<RetiredRow>,
+    pub outstanding_supply: Vec<SupplyRow>,
+    pub reconciliation: Vec<ReconciliationRow>,
+    pub certificates: Vec<CertificateRow>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IssuedRow {
+    pub rwa_mint: String,
+    pub minter: String,
+    pub minter_controller: String,
+    pub nft_mint: String,
+    pub available_credits: Option<u64>,
+    pub minted_credits: Option<u64>,
+    pub issued_in_period: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RetiredRow {
+    pub rwa_mint: String,
+    pub beneficiary: String,
+    pub certificates: u64,
+    pub retired_amount: u128,
+    pub retired_in_period: u128,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SupplyRow {
+    pub rwa_mint: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub supply: u64,
+}
+
+// minted credits in base units, less retirements and forced burns, must equal the supply
+#[derive(Serialize, Deserialize)]
+pub struct ReconciliationRow {
+    pub rwa_mint: String,
+    pub minted_amount: u128,
+    pub retired_amount: u128,
+    pub force_burned_amount: u128,
+    pub expected_supply: i128,
+    pub supply: u64,
+    pub difference: i128,
+    pub status: String,
+}
+
+// a certificate without beneficiary has had its nft burned
+#[derive(Serialize, Deserialize)]
+pub struct CertificateRow {
+    pub rwa_mint: String,
+    pub certificate_mint: String,
+    pub beneficiary: Option<String>,
+    pub amount: Option<u64>,
+}
+
+struct MintSnapshot {
+    mint: Pubkey,
+    symbol: String,
+    decimals: u8,
+    supply: u64,
+    // controller address, controller, available and minted credits
+    minters: Vec<(Pubkey, MinterController, Option<u64>, Option<u64>)>,
+    certificates: Vec<CertificateRow>,
+    force_burned: u128,
+}
+
+pub fn report(context: &Context, args: &ReportArgs) -> Result<Value> {
+    let previous = args
+        .previous
+        .as_ref()
+        .map(|path| -> Result<Report> {
+            let file = fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            Ok(serde_json::from_str(&file)?)
+        })
+        .transpose()?;
+
+    let clock: Clock = bincode::deserialize(&fetch(context, &clock::ID)?.data)?;
+    let snapshots = args
+        .mints
+        .iter()
+        .map(|mint| snapshot(context, mint))
+        .collect::<Result<Vec<_>>>()?;
+    let report = build(
+        clock.slot,
+        clock.unix_timestamp,
+        snapshots,
+        previous.as_ref(),
+    );
+
+    if let Some(dir) = &args.csv_dir {
+        fs::create_dir_all(dir)?;
+        write_csv(
+            &dir.join("issued_per_minter.csv"),
+            &report.issued_per_minter,
+        )?;
+        write_csv(
+            &dir.join("retired_per_beneficiary.csv"),
+            &report.retired_per_beneficiary,
+        )?;
+        write_csv(
+            &dir.join("outstanding_supply.csv"),
+            &report.outstanding_supply,
+        )?;
+        write_csv(&dir.join("reconciliation.csv"), &report.reconciliation)?;
+        write_csv(&dir.join("certificates.csv"), &report.certificates)?;
+        let mismatches = report
+            .reconciliation
+            .iter()
+            .filter(|row| row.status != "ok")
+            .count();
+        return Ok(json!({
+            "slot": report.slot,
+            "csv_dir": dir.display().to_string(),
+            "mismatches": mismatches,
+        }));
+    }
+    Ok(serde_json::to_value(report)?)
+}
+
+fn fetch(context: &Context, address: &Pubkey) -> Result<RpcAccount> {
+    context
+        .rpc
+        .get_account_with_commitment(address, context.rpc.commitment())?
+        .value
+        .with_context(|| format!("account {address} not found"))
+}
+
+fn program_accounts(
+    context: &Context,
+    program: &Pubkey,
+    filters: Vec<(usize, &[u8])>,
+) -> Result<Vec<(Pubkey, RpcAccount)>> {
+    let filters = filters
+        .into_iter()
+        .map(|(offset, bytes)| RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes.to_vec())))
+        .collect();
+    Ok(context.rpc.get_program_accounts_with_config(
+        program,
+        RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )?)
+}
+
+fn snapshot(context: &Context, mint: &Pubkey) -> Result<MintSnapshot> {
+    let data = fetch(context, mint)?.data;
+    let state = StateWithExtensions::<Mint>::unpack(&data)?;
+    let metadata = token_metadata(&data)?;
+
+    // controllers and compliance actions both start with the rwa mint
+    let mut minters = Vec::new();
+    for (address, account) in program_accounts(
+        context,
+        &rwa_tokenization::ID,
+        vec![(0, MinterController::DISCRIMINATOR), (8, mint.as_ref())],
+    )? {
+        let controller = minter_controller(&account.data)?;
+        let nft_metadata = token_metadata(&fetch(context, &controller.mint)?.data)?;
+        minters.push((
+            address,
+            controller,
+            credits(&nft_metadata, AVAILABLE_CREDITS_KEY),
+            credits(&nft_metadata, MINTED_CREDITS_KEY),
+        ));
+    }
+
+    let mut force_burned = 0;
+    for (_, account) in program_accounts(
+        context,
+        &rwa_tokenization::ID,
+        vec![(0, ComplianceAction::DISCRIMINATOR), (8, mint.as_ref())],
+    )? {
+        let action = compliance_action(&account.data)?;
+        if action.kind == ComplianceActionKind::ForceBurn {
+            force_burned += action.amount as u128;
+        }
+    }
+
+    // anyone can copy the update authority, only verified certificates count as retirements
+    let authority = mint_authority_pda(mint);
+    let mut certificates = Vec::new();
+    for (certificate_mint, account) in program_accounts(
+        context,
+        &token_2022::ID,
+        vec![(RETIREMENT_CERT_AUTHORITY_OFFSET, authority.as_ref())],
+    )? {
+        let verification = verify_retirement_certificate(&certificate_mint, mint, |address| {
+            (*address == certificate_mint).then(|| FetchedAccount {
+                owner: account.owner,
+                data: account.data.clone(),
+            })
+        });
+        if !verification.is_genuine() {
+            continue;
+        }
+        certificates.push(CertificateRow {
+            rwa_mint: mint.to_string(),
+            certificate_mint: certificate_mint.to_string(),
+            beneficiary: holder(context, &certificate_mint)?.map(|owner| owner.to_string()),
+            amount: verification.retired_credits,
+        });
+    }
+
+    Ok(MintSnapshot {
+        mint: *mint,
+        symbol: metadata.symbol,
+        decimals: state.base.decimals,
+        supply: state.base.supply,
+        minters,
+        certificates,
+        force_burned,
+    })
+}
+
+// owner of the token account holding the certificate nft
+fn holder(context: &Context, certificate_mint: &Pubkey) -> Result<Option<Pubkey>> {
+    let largest = context.rpc.get_token_largest_accounts(certificate_mint)?;
+    let Some(balance) = largest.iter().find(|balance| balance.amount.amount == "1") else {
+        return Ok(None);
+    };
+    let data = fetch(context, &balance.address.parse()?)?.data;
+    Ok(Some(
+        StateWithExtensions::<Account>::unpack(&data)?.base.owner,
+    ))
+}
+
+fn build(
+    slot: u64,
+    timestamp: i64,
+    snapshots: Vec<MintSnapshot>,
+    previous: Option<&Report>,
+) -> Report {
+    let previous_minted: BTreeMap<&str, Option<u64>> = previous
+        .map(|report| {
+            report
+                .issued_per_minter
+                .iter()
+                .map(|row| (row.minter_controller.as_str(), row.minted_credits))
+                .collect()
+        })
+        .unwrap_or_default();
+    let previous_certificates: HashSet<&str> = previous
+        .map(|report| {
+            report
+                .certificates
+                .iter()
+                .map(|row| row.certificate_mint.as_str())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut report = Report {
+        slot,
+        timestamp,
+        period_start_slot: previous.map(|report| report.slot),
+        issued_per_minter: Vec::new(),
+        retired_per_beneficiary: Vec::new(),
+        outstanding_supply: Vec::new(),
+        reconciliation: Vec::new(),
+        certificates: Vec::new(),
+    };
+    for snapshot in snapshots {
+        let rwa_mint = snapshot.mint.to_string();
+        let scale = 10u128.pow(snapshot.decimals as u32);
+        let mut credits_readable = true;
+
+        let mut minted_amount = 0u128;
+        for (address, controller, available_credits, minted_credits) in snapshot.minters {
+            let minter_controller = address.to_string();
+            let issued_in_period = match previous_minted.get(minter_controller.as_str()) {
+                Some(before) => minted_credits
+                    .zip(*before)
+                    .map(|(now, before)| now.saturating_sub(before)),
+                None => minted_credits,
+            };
+            match minted_credits {
+                Some(minted) => minted_amount += minted as u128 * scale,
+                None => credits_readable = false,
+            }
+            report.issued_per_minter.push(IssuedRow {
+                rwa_mint: rwa_mint.clone(),
+                minter: controller.user.to_string(),
+                minter_controller,
+                nft_mint: controller.mint.to_string(),
+                available_credits,
+                minted_credits,
+                issued_in_period,
+            });
+        }
+
+        let mut retired_amount = 0u128;
+        let mut beneficiaries: BTreeMap<String, RetiredRow> = BTreeMap::new();
+        for certificate in &snapshot.certificates {
+            let Some(amount) = certificate.amount else {
+                credits_readable = false;
+                continue;
+            };
+            retired_amount += amount as u128;
+            let beneficiary = certificate
+                .beneficiary
+                .clone()
+                .unwrap_or_else(|| "burned".to_string());
+            let row = beneficiaries
+                .entry(beneficiary.clone())
+                .or_insert_with(|| RetiredRow {
+                    rwa_mint: rwa_mint.clone(),
+                    beneficiary,
+                    certificates: 0,
+                    retired_amount: 0,
+                    retired_in_period: 0,
+                });
+            row.certificates += 1;
+            row.retired_amount += amount as u128;
+            if !previous_certificates.contains(certificate.certificate_mint.as_str()) {
+                row.retired_in_period += amount as u128;
+            }
+        }
+        report
+            .retired_per_beneficiary
+            .extend(beneficiaries.into_values());
+        report.certificates.extend(snapshot.certificates);
+
+        report.outstanding_supply.push(SupplyRow {
+            rwa_mint: rwa_mint.clone(),
+            symbol: snapshot.symbol,
+            decimals: snapshot.decimals,
+            supply: snapshot.supply,
+        });
+
+        let expected_supply =
+            minted_amount as i128 - retired_amount as i128 - snapshot.force_burned as i128;
+        let difference = snapshot.supply as i128 - expected_supply;
+        let status = if !credits_readable {
+            "unreadable_credits"
+        } else if difference != 0 {
+            "mismatch"
+        } else {
+            "ok"
+        };
+        report.reconciliation.push(ReconciliationRow {
+            rwa_mint,
+            minted_amount,
+            retired_amount,
+            force_burned_amount: snapshot.force_burned,
+            expected_supply,
+            supply: snapshot.supply,
+            difference,
+            status: status.to_string(),
+        });
+    }
+    report
+}
+
+fn write_csv<T: Serialize>(path: &Path, rows: &[T]) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECIMALS: u8 = 2;
+
+    fn minter(
+        address: Pubkey,
+        minted_credits: Option<u64>,
+    ) -> (Pubkey, MinterController, Option<u64>, Option<u64>) {
+        let controller = MinterController {
+            rwa_mint: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            bump: 255,
+        };
+        (address, controller, Some(0), minted_credits)
+    }
+
+    fn certificate(
+        certificate_mint: Pubkey,
+        beneficiary: Option<&str>,
+        amount: u64,
+    ) -> CertificateRow {
+        CertificateRow {
+            rwa_mint: String::new(),
+            certificate_mint: certificate_mint.to_string(),
+            beneficiary: beneficiary.map(str::to_string),
+            amount: Some(amount),
+        }
+    }
+
+    fn snapshot(
+        supply: u64,
+        minters: Vec<(Pubkey, MinterController, Option<u64>, Option<u64>)>,
+        certificates: Vec<CertificateRow>,
+        force_burned: u128,
+    ) -> MintSnapshot {
+        MintSnapshot {
+            mint: Pubkey::new_unique(),
+            symbol: "CCT".to_string(),
+            decimals: DECIMALS,
+            supply,
+            minters,
+            certificates,
+            force_burned,
+        }
+    }
+
+    #[test]
+    fn reconciles_minted_credits_against_supply() {
+        // 10 credits are 1_000 base units, less 300 + 200 retired and 100 force burned
+        let certificates = vec![
+            certificate(Pubkey::new_unique(), Some("alice"), 300),
+            certificate(Pubkey::new_unique(), None, 200),
+        ];
+        let minters = vec![minter(Pubkey::new_unique(), Some(10))];
+        let report = build(1, 0, vec![snapshot(400, minters, certificates, 100)], None);
+
+        let row = &report.reconciliation[0];
+        assert_eq!(row.minted_amount, 1_000);
+        assert_eq!(row.retired_amount, 500);
+        assert_eq!(row.force_burned_amount, 100);
+        assert_eq!(row.expected_supply, 400);
+        assert_eq!(row.difference, 0);
+        assert_eq!(row.status, "ok");
+        let beneficiaries: Vec<_> = report
+            .retired_per_beneficiary
+            .iter()
+            .map(|row| (row.beneficiary.as_str(), row.retired_amount))
+            .collect();
+        assert_eq!(beneficiaries, [("alice", 300), ("burned", 200)]);
+
+        let minters = vec![minter(Pubkey::new_unique(), Some(10))];
+        let report = build(1, 0, vec![snapshot(401, minters, Vec::new(), 0)], None);
+        let row = &report.reconciliation[0];
+        assert_eq!(row.difference, -599);
+        assert_eq!(row.status, "mismatch");
+    }
+
+    #[test]
+    fn unreadable_credits_are_flagged() {
+        let minters = vec![minter(Pubkey::new_unique(), None)];
+        let report = build(1, 0, vec![snapshot(0, minters, Vec::new(), 0)], None);
+        assert_eq!(report.reconciliation[0].status, "unreadable_credits");
+        assert_eq!(report.issued_per_minter[0].issued_in_period, None);
+    }
+
+    #[test]
+    fn period_columns_hold_the_changes_since_the_previous_report() {
+        let controller = Pubkey::new_unique();
+        let old_certificate = Pubkey::new_unique();
+        let previous = build(
+            1,
+            0,
+            vec![snapshot(
+                200,
+                vec![minter(controller, Some(5))],
+                vec![certificate(old_certificate, Some("alice"), 300)],
+                0,
+            )],
+            None,
+        );
+        assert_eq!(previous.issued_per_minter[0].issued_in_period, Some(5));
+
+        // the same controller minted 3 more credits and a second minter started minting
+        let new_controller = Pubkey::new_unique();
+        let report = build(
+            2,
+            0,
+            vec![snapshot(
+                700,
+                vec![minter(controller, Some(8)), minter(new_controller, Some(4))],
+                vec![
+                    certificate(old_certificate, Some("alice"), 300),
+                    certificate(Pubkey::new_unique(), Some("alice"), 200),
+                ],
+                0,
+            )],
+            Some(&previous),
+        );
+        assert_eq!(report.period_start_slot, Some(1));
+        let issued: Vec<_> = report
+            .issued_per_minter
+            .iter()
+            .map(|row| (row.minter_controller.clone(), row.issued_in_period))
+            .collect();
+        assert_eq!(
+            issued,
+            [
+                (controller.to_string(), Some(3)),
+                (new_controller.to_string(), Some(4))
+            ]
+        );
+        let retired = &report.retired_per_beneficiary[0];
+        assert_eq!(retired.certificates, 2);
+        assert_eq!(retired.retired_amount, 500);
+        assert_eq!(retired.retired_in_period, 200);
+        assert_eq!(report.reconciliation[0].status, "ok");
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_spl::token_2022::spl_token_2022::{extension::StateWithExtensions, state::Mint};
+use anyhow::{anyhow, Result};
+use rwa_tokenization_client::{
+    config_pda, consumer_controller, consumer_controller_pda, consumer_nft_pda, credits,
+    governance_config, mint_authority, mint_authority_pda, minter_controller,
+    minter_controller_pda, minter_nft_pda,
+    rwa_tokenization::{AVAILABLE_CREDITS_KEY, MINTED_CREDITS_KEY},
+    token_metadata,
+};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{args::ShowCommand, commands::Context};
+
+pub fn show(context: &Context, command: &ShowCommand) -> Result<Value> {
+    match command {
+        ShowCommand::Config => show_config(context),
+        ShowCommand::Mint { mint } => show_mint(context, mint),
+        ShowCommand::Minter { mint, minter } => show_minter(context, mint, minter),
+        ShowCommand::Consumer { mint, consumer } => show_consumer(context, mint, consumer),
+    }
+}
+
+fn fetch(context: &Context, address: &Pubkey) -> Result<Vec<u8>> {
+    context
+        .rpc
+        .get_account_with_commitment(address, context.rpc.commitment())?
+        .value
+        .map(|account| account.data)
+        .ok_or_else(|| anyhow!("account {address} not found"))
+}
+
+fn show_config(context: &Context) -> Result<Value> {
+    let address = config_pda();
+    let config = governance_config(&fetch(context, &address)?)?;
+    Ok(json!({
+        "address": address.to_string(),
+        "authority": config.authority.to_string(),
+        "is_initialized": config.is_initialized,
+    }))
+}
+
+fn show_mint(context: &Context, mint: &Pubkey) -> Result<Value> {
+    let data = fetch(context, mint)?;
+    let state = StateWithExtensions::<Mint>::unpack(&data)?;
+    let metadata = token_metadata(&data)?;
+    let authority_address = mint_authority_pda(mint);
+    let authority = mint_authority(&fetch(context, &authority_address)?)?;
+    Ok(json!({
+        "address": mint.to_string(),
+        "name": metadata.name,
+        "symbol": metadata.symbol,
+        "uri": metadata.uri,
+        "decimals": state.base.decimals,
+        "supply": state.base.supply,
+        "mint_authority": authority_address.to_string(),
+        "governance_authority": authority.authority.to_string(),
+        "transfer_hook": authority.transfer_hook.map(|program| program.to_string()),
+    }))
+}
+
+fn show_minter(context: &Context, mint: &Pubkey, minter: &Pubkey) -> Result<Value> {
+    let nft_mint = minter_nft_pda(mint, minter);
+    let controller_address = minter_controller_pda(&nft_mint);
+    let controller = minter_controller(&fetch(context, &controller_address)?)?;
+    let metadata = token_metadata(&fetch(context, &nft_mint)?)?;
+    Ok(json!({
+        "minter": controller.user.to_string(),
+        "rwa_mint": controller.rwa_mint.to_string(),
+        "nft_mint": nft_mint.to_string(),
+        "controller": controller_address.to_string(),
+        "available_credits": credits(&metadata, AVAILABLE_CREDITS_KEY),
+        "minted_credits": credits(&metadata, MINTED_CREDITS_KEY),
+    }))
+}
+
+fn show_consumer(context: &Context, mint: &Pubkey, consumer: &Pubkey) -> Result<Value> {
+    let nft_mint = consumer_nft_pda(mint, consumer);
+    let controller_address = consumer_controller_pda(&nft_mint);
+    let controller = consumer_controller(&fetch(context, &controller_address)?)?;
+    let jurisdiction = controller.jurisdiction;
+    Ok(json!({
+        "consumer": controller.user.to_string(),
+        "rwa_mint": controller.rwa_mint.to_string(),
+        "nft_mint": nft_mint.to_string(),
+        "controller": controller_address.to_string(),
+        "country": String::from_utf8_lossy(&jurisdiction.country_code),
+        "investor_class": format!("{:?}", jurisdiction.investor_class),
+    }))
+}
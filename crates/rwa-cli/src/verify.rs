@@ -0,0 +1,48 @@
+use anyhow::Result;
+use rwa_tokenization_client::{verify_retirement_certificate, FetchedAccount};
+use serde_json::{json, Map, Value};
+
+use crate::{args::VerifyCertificateArgs, commands::Context};
+
+pub fn verify_certificate(context: &Context, args: &VerifyCertificateArgs) -> Result<Value> {
+    let mut error = None;
+    let verification = verify_retirement_certificate(&args.certificate, &args.mint, |address| {
+        match context
+            .rpc
+            .get_account_with_commitment(address, context.rpc.commitment())
+        {
+            Ok(response) => response.value.map(|account| FetchedAccount {
+                owner: account.owner,
+                data: account.data,
+            }),
+            Err(err) => {
+                error.get_or_insert(err);
+                None
+            }
+        }
+    });
+    // an unreachable node must not read as a forged certificate
+    if let Some(error) = error {
+        return Err(error.into());
+    }
+
+    let checks: Map<String, Value> = verification
+        .checks
+        .iter()
+        .map(|check| {
+            let status = if check.passed { "ok" } else { "FAILED" };
+            (
+                check.name.to_string(),
+                format!("{status}: {}", check.detail).into(),
+            )
+        })
+        .collect();
+    Ok(json!({
+        "certificate": verification.certificate_mint.to_string(),
+        "genuine": verification.is_genuine(),
+        "rwa_mint": verification.rwa_mint.to_string(),
+        "retired_credits": verification.retired_credits,
+        "retired_by": verification.retired_by.map(|consumer| consumer.to_string()),
+        "checks": checks,
+    }))
+}
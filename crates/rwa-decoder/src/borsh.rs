@@ -0,0 +1,183 @@
+use serde_json::{Map, Value};
+
+use crate::{
+    error::DecodeError,
+    idl::{Idl, IdlFields, IdlType, IdlTypeDefTy},
+};
+
+/// Little-endian reader over account bytes.
+pub struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.data.len() < len {
+            return Err(DecodeError::InvalidData(format!(
+                "expected {len} more bytes, {} left",
+                self.data.len()
+            )));
+        }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(head)
+    }
+
+    pub fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        Ok(self.take(N)?.try_into().expect("took N bytes"))
+    }
+
+    pub fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> Result<u16, DecodeError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    pub fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    pub fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    pub fn pubkey(&mut self) -> Result<String, DecodeError> {
+        Ok(bs58::encode(self.take(32)?).into_string())
+    }
+
+    pub fn string(&mut self) -> Result<String, DecodeError> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.take(len)?.to_vec())
+            .map_err(|_| DecodeError::InvalidData("string is not utf-8".to_string()))
+    }
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+// `MinterController` -> `minter_controller`, also used for enum variants
+pub fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, char) in name.chars().enumerate() {
+        if char.is_ascii_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.push(char.to_ascii_lowercase());
+    }
+    snake
+}
+
+// 64 and 128-bit integers become strings so JavaScript does not round them, byte arrays hex
+pub fn decode_type(idl: &Idl, ty: &IdlType, reader: &mut Reader) -> Result<Value, DecodeError> {
+    Ok(match ty {
+        IdlType::Primitive(name) => match name.as_str() {
+            "bool" => Value::Bool(reader.u8()? != 0),
+            "u8" => reader.u8()?.into(),
+            "i8" => i8::from_le_bytes(reader.array()?).into(),
+            "u16" => reader.u16()?.into(),
+            "i16" => i16::from_le_bytes(reader.array()?).into(),
+            "u32" => reader.u32()?.into(),
+            "i32" => i32::from_le_bytes(reader.array()?).into(),
+            "f32" => f32::from_le_bytes(reader.array()?).into(),
+            "f64" => f64::from_le_bytes(reader.array()?).into(),
+            "u64" => reader.u64()?.to_string().into(),
+            "i64" => i64::from_le_bytes(reader.array()?).to_string().into(),
+            "u128" => u128::from_le_bytes(reader.array()?).to_string().into(),
+            "i128" => i128::from_le_bytes(reader.array()?).to_string().into(),
+            "string" => reader.string()?.into(),
+            "pubkey" => reader.pubkey()?.into(),
+            "bytes" => {
+                let len = reader.u32()? as usize;
+                hex(reader.take(len)?).into()
+            }
+            other => {
+                return Err(DecodeError::InvalidData(format!(
+                    "unsupported IDL type {other}"
+                )))
+            }
+        },
+        IdlType::Option { option } => match reader.u8()? {
+            0 => Value::Null,
+            _ => decode_type(idl, option, reader)?,
+        },
+        IdlType::Vec { vec } => {
+            let len = reader.u32()? as usize;
+            if is_u8(vec) {
+                return Ok(hex(reader.take(len)?).into());
+            }
+            let items = (0..len)
+                .map(|_| decode_type(idl, vec, reader))
+                .collect::<Result<_, _>>()?;
+            Value::Array(items)
+        }
+        IdlType::Array { array: (item, len) } => {
+            if is_u8(item) {
+                return Ok(hex(reader.take(*len)?).into());
+            }
+            let items = (0..*len)
+                .map(|_| decode_type(idl, item, reader))
+                .collect::<Result<_, _>>()?;
+            Value::Array(items)
+        }
+        IdlType::Defined { defined } => decode_defined(idl, &defined.name, reader)?,
+    })
+}
+
+pub fn decode_defined(idl: &Idl, name: &str, reader: &mut Reader) -> Result<Value, DecodeError> {
+    let def = idl
+        .type_def(name)
+        .ok_or_else(|| DecodeError::InvalidData(format!("type {name} is not in the IDL")))?;
+    match &def.ty {
+        IdlTypeDefTy::Struct { fields } => decode_fields(idl, fields.as_ref(), reader),
+        IdlTypeDefTy::Enum { variants } => {
+            let index = reader.u8()? as usize;
+            let variant = variants.get(index).ok_or_else(|| {
+                DecodeError::InvalidData(format!("{name} has no variant {index}"))
+            })?;
+            let variant_name = snake_case(&variant.name);
+            match &variant.fields {
+                None => Ok(variant_name.into()),
+                Some(fields) => {
+                    let mut object = Map::new();
+                    object.insert(variant_name, decode_fields(idl, Some(fields), reader)?);
+                    Ok(Value::Object(object))
+                }
+            }
+        }
+        IdlTypeDefTy::Type { alias } => decode_type(idl, alias, reader),
+    }
+}
+
+fn decode_fields(
+    idl: &Idl,
+    fields: Option<&IdlFields>,
+    reader: &mut Reader,
+) -> Result<Value, DecodeError> {
+    match fields {
+        None => Ok(Value::Object(Map::new())),
+        Some(IdlFields::Named(fields)) => {
+            let mut object = Map::new();
+            for field in fields {
+                object.insert(field.name.clone(), decode_type(idl, &field.ty, reader)?);
+            }
+            Ok(Value::Object(object))
+        }
+        Some(IdlFields::Tuple(types)) => Ok(Value::Array(
+            types
+                .iter()
+                .map(|ty| decode_type(idl, ty, reader))
+                .collect::<Result<_, _>>()?,
+        )),
+    }
+}
+
+fn is_u8(ty: &IdlType) -> bool {
+    matches!(ty, IdlType::Primitive(name) if name == "u8")
+}
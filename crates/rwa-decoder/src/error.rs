@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The owner is not Token-2022, rwa-tokenization or token-transfer-hook.
+    UnknownOwner(String),
+    /// A program account whose discriminator is not in the program's IDL.
+    UnknownAccount(String),
+    /// A Token-2022 account that is not a mint created by rwa-tokenization.
+    NotAnRwaMint(String),
+    /// The bytes do not match the layout they claim to have.
+    InvalidData(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownOwner(owner) => write!(f, "accounts owned by {owner} are not decoded"),
+            Self::UnknownAccount(program) => write!(f, "unknown {program} account"),
+            Self::NotAnRwaMint(reason) => write!(f, "not an rwa-tokenization mint: {reason}"),
+            Self::InvalidData(reason) => write!(f, "invalid account data: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
@@ -0,0 +1,87 @@
+use serde::Deserialize;
+
+// the subset of the Anchor IDL spec 0.1.0 needed to decode accounts
+
+#[derive(Deserialize)]
+pub struct Idl {
+    pub address: String,
+    pub metadata: IdlMetadata,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccount>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+}
+
+#[derive(Deserialize)]
+pub struct IdlMetadata {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefTy,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IdlTypeDefTy {
+    Struct { fields: Option<IdlFields> },
+    Enum { variants: Vec<IdlVariant> },
+    Type { alias: IdlType },
+}
+
+#[derive(Deserialize)]
+pub struct IdlVariant {
+    pub name: String,
+    pub fields: Option<IdlFields>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum IdlFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum IdlType {
+    Primitive(String),
+    Option { option: Box<IdlType> },
+    Vec { vec: Box<IdlType> },
+    Array { array: (Box<IdlType>, usize) },
+    Defined { defined: IdlDefined },
+}
+
+#[derive(Deserialize)]
+pub struct IdlDefined {
+    pub name: String,
+}
+
+impl Idl {
+    pub fn account(&self, discriminator: &[u8]) -> Option<&IdlAccount> {
+        self.accounts
+            .iter()
+            .find(|account| account.discriminator == discriminator)
+    }
+
+    pub fn type_def(&self, name: &str) -> Option<&IdlTypeDef> {
+        self.types.iter().find(|def| def.name == name)
+    }
+}
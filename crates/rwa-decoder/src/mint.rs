@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{borsh::Reader, error::DecodeError};
+
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+// same keys as the rwa-tokenization constants, kept here so the crate builds without the program
+pub const AVAILABLE_CREDITS_KEY: &str = "available_credits";
+pub const MINTED_CREDITS_KEY: &str = "minted_credits";
+pub const RETIRED_CREDITS_KEY: &str = "retired_credits";
+
+// Token-2022 layout: the 82 byte base mint, padding up to the account type at 165, then TLV
+// extensions
+const MINT_LEN: usize = 82;
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+const TRANSFER_FEE_CONFIG: u16 = 1;
+const MINT_CLOSE_AUTHORITY: u16 = 3;
+const DEFAULT_ACCOUNT_STATE: u16 = 6;
+const PERMANENT_DELEGATE: u16 = 12;
+const TRANSFER_HOOK: u16 = 14;
+const METADATA_POINTER: u16 = 18;
+const TOKEN_METADATA: u16 = 19;
+
+#[derive(Serialize)]
+pub struct MintData {
+    pub mint_authority: Option<String>,
+    pub supply: String,
+    pub decimals: u8,
+    pub freeze_authority: Option<String>,
+    pub metadata: Option<TokenMetadata>,
+    pub credits: Credits,
+    pub extensions: MintExtensions,
+}
+
+#[derive(Serialize)]
+pub struct TokenMetadata {
+    pub update_authority: Option<String>,
+    pub mint: String,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub additional_metadata: BTreeMap<String, String>,
+}
+
+/// Credit counters of certificate mints, as stored in their metadata: minter certificates
+/// count whole credits, retirement certificates base units of the RWA mint.
+#[derive(Serialize, Default)]
+pub struct Credits {
+    pub available_credits: Option<String>,
+    pub minted_credits: Option<String>,
+    pub retired_credits: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+pub struct MintExtensions {
+    pub close_authority: Option<String>,
+    pub permanent_delegate: Option<String>,
+    pub default_account_state: Option<&'static str>,
+    pub transfer_hook_program: Option<String>,
+    pub transfer_fee: Option<TransferFee>,
+    pub metadata_pointer: Option<MetadataPointer>,
+}
+
+/// The newer of the two fees in the config, active from `epoch`.
+#[derive(Serialize)]
+pub struct TransferFee {
+    pub epoch: String,
+    pub basis_points: u16,
+    pub maximum_fee: String,
+}
+
+#[derive(Serialize)]
+pub struct MetadataPointer {
+    pub authority: Option<String>,
+    pub metadata_address: Option<String>,
+}
+
+pub fn decode_mint(data: &[u8]) -> Result<MintData, DecodeError> {
+    if data.len() < MINT_LEN {
+        return Err(DecodeError::NotAnRwaMint(format!(
+            "{} bytes is too short for a mint",
+            data.len()
+        )));
+    }
+    if data.len() > MINT_LEN && data.get(ACCOUNT_TYPE_OFFSET) != Some(&ACCOUNT_TYPE_MINT) {
+        return Err(DecodeError::NotAnRwaMint(
+            "Token-2022 account is not a mint".to_string(),
+        ));
+    }
+
+    let mut reader = Reader::new(&data[..MINT_LEN]);
+    let mint_authority = coption_pubkey(&mut reader)?;
+    let supply = reader.u64()?;
+    let decimals = reader.u8()?;
+    if reader.u8()? == 0 {
+        return Err(DecodeError::NotAnRwaMint(
+            "mint is not initialized".to_string(),
+        ));
+    }
+    let freeze_authority = coption_pubkey(&mut reader)?;
+
+    let mut metadata = None;
+    let mut extensions = MintExtensions::default();
+    let mut tlv = Reader::new(data.get(ACCOUNT_TYPE_OFFSET + 1..).unwrap_or_default());
+    while let Ok(extension_type) = tlv.u16() {
+        if extension_type == 0 {
+            break;
+        }
+        let len = tlv.u16()? as usize;
+        let mut value = Reader::new(tlv.take(len)?);
+        match extension_type {
+            TRANSFER_FEE_CONFIG => {
+                // both authorities, withheld amount and the older fee come first
+                value.take(32 + 32 + 8 + 18)?;
+                extensions.transfer_fee = Some(TransferFee {
+                    epoch: value.u64()?.to_string(),
+                    maximum_fee: value.u64()?.to_string(),
+                    basis_points: value.u16()?,
+                });
+            }
+            MINT_CLOSE_AUTHORITY => extensions.close_authority = optional_pubkey(&mut value)?,
+            DEFAULT_ACCOUNT_STATE => {
+                extensions.default_account_state = Some(match value.u8()? {
+                    1 => "initialized",
+                    2 => "frozen",
+                    _ => "uninitialized",
+                })
+            }
+            PERMANENT_DELEGATE => extensions.permanent_delegate = optional_pubkey(&mut value)?,
+            TRANSFER_HOOK => {
+                value.take(32)?;
+                extensions.transfer_hook_program = optional_pubkey(&mut value)?;
+            }
+            METADATA_POINTER => {
+                extensions.metadata_pointer = Some(MetadataPointer {
+                    authority: optional_pubkey(&mut value)?,
+                    metadata_address: optional_pubkey(&mut value)?,
+                })
+            }
+            TOKEN_METADATA => metadata = Some(token_metadata(&mut value)?),
+            _ => {}
+        }
+    }
+
+    let credits = metadata
+        .as_ref()
+        .map(|metadata| Credits {
+            available_credits: credit(metadata, AVAILABLE_CREDITS_KEY),
+            minted_credits: credit(metadata, MINTED_CREDITS_KEY),
+            retired_credits: credit(metadata, RETIRED_CREDITS_KEY),
+        })
+        .unwrap_or_default();
+
+    Ok(MintData {
+        mint_authority,
+        supply: supply.to_string(),
+        decimals,
+        freeze_authority,
+        metadata,
+        credits,
+        extensions,
+    })
+}
+
+/// What rwa-tokenization created the mint as, from its metadata and authorities alone: RWA
+/// mints keep the `MintAuthority` PDA as both freeze and metadata update authority, certificates
+/// have no freeze authority and carry their credit counters. This is not a proof of origin,
+/// check retirement certificates with the client's verifier before trusting them.
+pub fn mint_kind(mint: &MintData) -> Result<&'static str, DecodeError> {
+    let Some(metadata) = &mint.metadata else {
+        return Err(DecodeError::NotAnRwaMint(
+            "mint has no token metadata".to_string(),
+        ));
+    };
+    if mint.credits.retired_credits.is_some() {
+        return Ok("retirement_certificate");
+    }
+    if mint.credits.available_credits.is_some() || mint.credits.minted_credits.is_some() {
+        return Ok("minter_certificate");
+    }
+    if mint.freeze_authority.is_some() && mint.freeze_authority == metadata.update_authority {
+        return Ok("rwa_mint");
+    }
+    if mint.freeze_authority.is_none() && mint.decimals == 0 {
+        return Ok("consumer_certificate");
+    }
+    Err(DecodeError::NotAnRwaMint(
+        "authorities do not match an rwa-tokenization mint".to_string(),
+    ))
+}
+
+fn credit(metadata: &TokenMetadata, key: &str) -> Option<String> {
+    let value = metadata.additional_metadata.get(key)?;
+    value.parse::<u64>().ok().map(|credits| credits.to_string())
+}
+
+fn token_metadata(reader: &mut Reader) -> Result<TokenMetadata, DecodeError> {
+    let update_authority = optional_pubkey(reader)?;
+    let mint = reader.pubkey()?;
+    let name = reader.string()?;
+    let symbol = reader.string()?;
+    let uri = reader.string()?;
+    let count = reader.u32()?;
+    let mut additional_metadata = BTreeMap::new();
+    for _ in 0..count {
+        let key = reader.string()?;
+        additional_metadata.insert(key, reader.string()?);
+    }
+    Ok(TokenMetadata {
+        update_authority,
+        mint,
+        name,
+        symbol,
+        uri,
+        additional_metadata,
+    })
+}
+
+// `COption<Pubkey>` of the base mint: a u32 tag, then the key
+fn coption_pubkey(reader: &mut Reader) -> Result<Option<String>, DecodeError> {
+    let tag = reader.u32()?;
+    let key = reader.pubkey()?;
+    Ok((tag == 1).then_some(key))
+}
+
+// `OptionalNonZeroPubkey` of the extensions: all zeros for none
+fn optional_pubkey(reader: &mut Reader) -> Result<Option<String>, DecodeError> {
+    let key = reader.take(32)?;
+    Ok(key
+        .iter()
+        .any(|byte| *byte != 0)
+        .then(|| bs58::encode(key).into_string()))
+}
@@ -0,0 +1,12 @@
+use wasm_bindgen::prelude::*;
+
+/// JSON of the decoded account, see `DecodedAccount`.
+#[wasm_bindgen(js_name = decodeAccount)]
+pub fn decode_account(owner: &str, data: &[u8]) -> Result<String, JsError> {
+    crate::decode_account_json(owner, data).map_err(|err| JsError::new(&err.to_string()))
+}
+
+#[wasm_bindgen(js_name = schemaVersion)]
+pub fn schema_version() -> u32 {
+    crate::SCHEMA_VERSION
+}
@@ -0,0 +1,193 @@
+use anchor_lang::{prelude::Pubkey, AccountSerialize, Discriminator};
+use rwa_decoder::{decode_account, DecodeError, SCHEMA_VERSION};
+use rwa_tokenization::{ComplianceAction, ComplianceActionKind, ConsumerController};
+use serde_json::json;
+use token_transfer_hook::{
+    InvestorClass, Jurisdiction, JurisdictionRule, JurisdictionRules, TradingWindow, TransferPolicy,
+};
+
+fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn bundled_idls_match_the_programs() {
+    let rwa = rwa_tokenization::ID.to_string();
+    let hook = token_transfer_hook::ID.to_string();
+    let accounts: [(&str, &str, &[u8]); 11] = [
+        (
+            &rwa,
+            "account_hold",
+            rwa_tokenization::AccountHold::DISCRIMINATOR,
+        ),
+        (&rwa, "compliance_action", ComplianceAction::DISCRIMINATOR),
+        (
+            &rwa,
+            "compliance_role",
+            rwa_tokenization::ComplianceRole::DISCRIMINATOR,
+        ),
+        (
+            &rwa,
+            "consumer_controller",
+            ConsumerController::DISCRIMINATOR,
+        ),
+        (&rwa, "denylist", rwa_tokenization::Denylist::DISCRIMINATOR),
+        (
+            &rwa,
+            "governance_config",
+            rwa_tokenization::GovernanceConfig::DISCRIMINATOR,
+        ),
+        (&rwa, "lockup", rwa_tokenization::Lockup::DISCRIMINATOR),
+        (
+            &rwa,
+            "mint_authority",
+            rwa_tokenization::MintAuthority::DISCRIMINATOR,
+        ),
+        (
+            &rwa,
+            "minter_controller",
+            rwa_tokenization::MinterController::DISCRIMINATOR,
+        ),
+        (&hook, "transfer_policy", TransferPolicy::DISCRIMINATOR),
+        (
+            &hook,
+            "transfer_stats",
+            token_transfer_hook::TransferStats::DISCRIMINATOR,
+        ),
+    ];
+    for (owner, kind, discriminator) in accounts {
+        // zeroed fields decode for every account, so a mismatch can only come from the IDL
+        let mut data = discriminator.to_vec();
+        data.resize(1_024, 0);
+        let decoded = decode_account(owner, &data).unwrap();
+        assert_eq!(decoded.kind, kind);
+    }
+    assert_eq!(
+        rwa_decoder::AVAILABLE_CREDITS_KEY,
+        rwa_tokenization::AVAILABLE_CREDITS_KEY
+    );
+    assert_eq!(
+        rwa_decoder::MINTED_CREDITS_KEY,
+        rwa_tokenization::MINTED_CREDITS_KEY
+    );
+    assert_eq!(
+        rwa_decoder::RETIRED_CREDITS_KEY,
+        rwa_tokenization::RETIRED_CREDITS_KEY
+    );
+}
+
+#[test]
+fn program_accounts_decode_to_the_v1_schema() {
+    let mint = Pubkey::new_from_array([1; 32]);
+    let officer = Pubkey::new_from_array([2; 32]);
+    let source = Pubkey::new_from_array([3; 32]);
+    let action = ComplianceAction {
+        mint,
+        action_id: 7,
+        kind: ComplianceActionKind::ForceBurn,
+        authority: officer,
+        source,
+        destination: None,
+        amount: u64::MAX,
+        reason: "court order".to_string(),
+        document_hash: [0xab; 32],
+        created_at: -1,
+        bump: 254,
+    };
+    let decoded = decode_account(&rwa_tokenization::ID.to_string(), &serialize(&action)).unwrap();
+    assert_eq!(
+        serde_json::to_value(&decoded).unwrap(),
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "program": "rwa_tokenization",
+            "kind": "compliance_action",
+            "data": {
+                "mint": mint.to_string(),
+                "action_id": "7",
+                "kind": "force_burn",
+                "authority": officer.to_string(),
+                "source": source.to_string(),
+                "destination": null,
+                "amount": "18446744073709551615",
+                "reason": "court order",
+                "document_hash": "ab".repeat(32),
+                "created_at": "-1",
+                "bump": 254,
+            },
+        })
+    );
+
+    let consumer = ConsumerController {
+        rwa_mint: mint,
+        mint: source,
+        user: officer,
+        bump: 1,
+        jurisdiction: Jurisdiction {
+            country_code: *b"US",
+            investor_class: InvestorClass::Institutional,
+        },
+    };
+    let decoded = decode_account(&rwa_tokenization::ID.to_string(), &serialize(&consumer)).unwrap();
+    assert_eq!(
+        decoded.data["jurisdiction"],
+        json!({ "country_code": "5553", "investor_class": "institutional" })
+    );
+
+    let mut policy = TransferPolicy::permissioned(mint);
+    policy.min_amount = Some(10);
+    policy.trading_window = Some(TradingWindow {
+        start_hour: 22,
+        end_hour: 6,
+    });
+    policy.allowed_accounts = vec![source];
+    policy.jurisdiction_rules = Some(JurisdictionRules {
+        default_allowed: false,
+        rules: vec![JurisdictionRule {
+            jurisdiction: Jurisdiction {
+                country_code: *b"DE",
+                investor_class: InvestorClass::Retail,
+            },
+            can_send: true,
+            can_receive: false,
+        }],
+    });
+    let decoded =
+        decode_account(&token_transfer_hook::ID.to_string(), &serialize(&policy)).unwrap();
+    assert_eq!(decoded.program, "token_transfer_hook");
+    assert_eq!(decoded.kind, "transfer_policy");
+    assert_eq!(decoded.data["min_amount"], "10");
+    assert_eq!(decoded.data["max_amount"], json!(null));
+    assert_eq!(
+        decoded.data["trading_window"],
+        json!({ "start_hour": 22, "end_hour": 6 })
+    );
+    assert_eq!(
+        decoded.data["allowed_accounts"],
+        json!([source.to_string()])
+    );
+    assert_eq!(
+        decoded.data["jurisdiction_rules"]["rules"][0]["jurisdiction"]["investor_class"],
+        "retail"
+    );
+}
+
+#[test]
+fn unknown_accounts_are_rejected() {
+    let rwa = rwa_tokenization::ID.to_string();
+    assert_eq!(
+        decode_account(&rwa, &[0; 64]).err(),
+        Some(DecodeError::UnknownAccount("rwa_tokenization".to_string()))
+    );
+    let system = Pubkey::default().to_string();
+    assert_eq!(
+        decode_account(&system, &[]).err(),
+        Some(DecodeError::UnknownOwner(system))
+    );
+    let truncated = rwa_tokenization::GovernanceConfig::DISCRIMINATOR;
+    assert!(matches!(
+        decode_account(&rwa, truncated),
+        Err(DecodeError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,243 @@
+use anchor_lang::{event::EVENT_IX_TAG_LE, prelude::Pubkey, AnchorDeserialize, Discriminator};
+use rwa_tokenization_client::{
+    rwa_tokenization::{self, events::*, instruction},
+    token_transfer_hook::{self, TransferEvent},
+};
+
+// an instruction of the transaction, outer or inner, in execution order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub succeeded: bool,
+    pub instructions: Vec<RawInstruction>,
+}
+
+pub enum Event {
+    ConfigInitialized(ConfigInitialized),
+    MinterCertIssued(MinterCertIssued),
+    QuotaUpdated(QuotaUpdated),
+    ConsumerCertIssued(ConsumerCertIssued),
+    RwaTokenCreated(RwaTokenCreated),
+    CreditsMinted(CreditsMinted),
+    CreditsRetired(CreditsRetired),
+    Transfer(TransferEvent),
+}
+
+// certificate and wallet of a lost holder and of the recovery wallet
+pub struct HolderMigration {
+    pub rwa_mint: Pubkey,
+    pub old_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+    pub old_nft_mint: Pubkey,
+    pub new_nft_mint: Pubkey,
+}
+
+// instructions without an event whose effect the registry tracks, read from their accounts
+// and args
+pub enum Action {
+    MigrateMinterHolder(HolderMigration),
+    MigrateConsumerHolder(HolderMigration),
+    ForceTransfer {
+        rwa_mint: Pubkey,
+        source_token: Pubkey,
+        destination_token: Pubkey,
+        args: instruction::ForceTransfer,
+    },
+    ForceBurn {
+        rwa_mint: Pubkey,
+        source_token: Pubkey,
+        args: instruction::ForceBurn,
+    },
+    CloseRwaToken {
+        rwa_mint: Pubkey,
+    },
+}
+
+pub enum Decoded {
+    Instruction {
+        program: &'static str,
+        name: &'static str,
+        action: Option<Action>,
+    },
+    Event(Event),
+}
+
+pub const RWA_PROGRAM: &str = "rwa-tokenization";
+pub const HOOK_PROGRAM: &str = "token-transfer-hook";
+
+const RWA_INSTRUCTIONS: &[(&[u8], &str)] = {
+    use rwa_tokenization::instruction::*;
+    &[
+        (Initialize::DISCRIMINATOR, "initialize"),
+        (IssueMinterCert::DISCRIMINATOR, "issue_minter_cert"),
+        (UpdateQuotaCredit::DISCRIMINATOR, "update_quota_credit"),
+        (IssueConsumerCert::DISCRIMINATOR, "issue_consumer_cert"),
+        (InitRwaToken::DISCRIMINATOR, "init_rwa_token"),
+        (MintRwaToken::DISCRIMINATOR, "mint_rwa_token"),
+        (RetireToken::DISCRIMINATOR, "retire_token"),
+        (ThawForConsumer::DISCRIMINATOR, "thaw_for_consumer"),
+        (FreezeAccount::DISCRIMINATOR, "freeze_account"),
+        (ReleaseHold::DISCRIMINATOR, "release_hold"),
+        (ForceTransfer::DISCRIMINATOR, "force_transfer"),
+        (ForceBurn::DISCRIMINATOR, "force_burn"),
+        (MigrateMinterHolder::DISCRIMINATOR, "migrate_minter_holder"),
+        (
+            MigrateConsumerHolder::DISCRIMINATOR,
+            "migrate_consumer_holder",
+        ),
+        (CloseRwaToken::DISCRIMINATOR, "close_rwa_token"),
+        (
+            SetConsumerJurisdiction::DISCRIMINATOR,
+            "set_consumer_jurisdiction",
+        ),
+        (UpdateRwaMetadata::DISCRIMINATOR, "update_rwa_metadata"),
+        (SetTransferPolicy::DISCRIMINATOR, "set_transfer_policy"),
+        (SetAllowedAccount::DISCRIMINATOR, "set_allowed_account"),
+        (
+            SetAllowedProgramOwner::DISCRIMINATOR,
+            "set_allowed_program_owner",
+        ),
+        (
+            SetJurisdictionRules::DISCRIMINATOR,
+            "set_jurisdiction_rules",
+        ),
+        (GrantComplianceRole::DISCRIMINATOR, "grant_compliance_role"),
+        (
+            RevokeComplianceRole::DISCRIMINATOR,
+            "revoke_compliance_role",
+        ),
+        (AddToDenylist::DISCRIMINATOR, "add_to_denylist"),
+        (RemoveFromDenylist::DISCRIMINATOR, "remove_from_denylist"),
+        (
+            UpdateExtraAccountMetaList::DISCRIMINATOR,
+            "update_extra_account_meta_list",
+        ),
+    ]
+};
+
+const HOOK_INSTRUCTIONS: &[(&[u8], &str)] = {
+    use token_transfer_hook::instruction::*;
+    &[
+        (
+            InitializeExtraAccountMetaList::DISCRIMINATOR,
+            "initialize_extra_account_meta_list",
+        ),
+        (Execute::DISCRIMINATOR, "execute"),
+        (
+            UpdateExtraAccountMetaList::DISCRIMINATOR,
+            "update_extra_account_meta_list",
+        ),
+        (
+            CloseExtraAccountMetaList::DISCRIMINATOR,
+            "close_extra_account_meta_list",
+        ),
+        (SetTransferPolicy::DISCRIMINATOR, "set_transfer_policy"),
+        (
+            InitializeTransferStats::DISCRIMINATOR,
+            "initialize_transfer_stats",
+        ),
+        (SetAllowedAccount::DISCRIMINATOR, "set_allowed_account"),
+        (
+            SetAllowedProgramOwner::DISCRIMINATOR,
+            "set_allowed_program_owner",
+        ),
+        (
+            SetJurisdictionRules::DISCRIMINATOR,
+            "set_jurisdiction_rules",
+        ),
+    ]
+};
+
+pub fn decode(instruction: &RawInstruction) -> Option<Decoded> {
+    let (program, names) = if instruction.program_id == rwa_tokenization::ID {
+        (RWA_PROGRAM, RWA_INSTRUCTIONS)
+    } else if instruction.program_id == token_transfer_hook::ID {
+        (HOOK_PROGRAM, HOOK_INSTRUCTIONS)
+    } else {
+        return None;
+    };
+
+    // event cpi: the program invoking itself with the event as data
+    if let Some(event) = instruction.data.strip_prefix(EVENT_IX_TAG_LE) {
+        return decode_event(event).map(Decoded::Event);
+    }
+    let &(_, name) = names
+        .iter()
+        .find(|(discriminator, _)| instruction.data.starts_with(discriminator))?;
+    let action = if program == RWA_PROGRAM {
+        decode_action(instruction)
+    } else {
+        None
+    };
+    Some(Decoded::Instruction {
+        program,
+        name,
+        action,
+    })
+}
+
+fn parse<T: Discriminator + AnchorDeserialize>(data: &[u8]) -> Option<T> {
+    let mut body = data.strip_prefix(T::DISCRIMINATOR)?;
+    T::deserialize(&mut body).ok()
+}
+
+// account positions follow the fields of the instruction's accounts struct
+fn decode_action(instruction: &RawInstruction) -> Option<Action> {
+    let data = &instruction.data;
+    let account = |index: usize| instruction.accounts.get(index).copied();
+    let migration = || {
+        Some(HolderMigration {
+            old_wallet: account(2)?,
+            new_wallet: account(3)?,
+            rwa_mint: account(5)?,
+            old_nft_mint: account(7)?,
+            new_nft_mint: account(10)?,
+        })
+    };
+
+    if parse::<instruction::MigrateMinterHolder>(data).is_some() {
+        migration().map(Action::MigrateMinterHolder)
+    } else if parse::<instruction::MigrateConsumerHolder>(data).is_some() {
+        migration().map(Action::MigrateConsumerHolder)
+    } else if let Some(args) = parse::<instruction::ForceTransfer>(data) {
+        Some(Action::ForceTransfer {
+            rwa_mint: account(3)?,
+            source_token: account(4)?,
+            destination_token: account(5)?,
+            args,
+        })
+    } else if let Some(args) = parse::<instruction::ForceBurn>(data) {
+        Some(Action::ForceBurn {
+            rwa_mint: account(3)?,
+            source_token: account(4)?,
+            args,
+        })
+    } else if parse::<instruction::CloseRwaToken>(data).is_some() {
+        Some(Action::CloseRwaToken {
+            rwa_mint: account(3)?,
+        })
+    } else {
+        None
+    }
+}
+
+fn decode_event(data: &[u8]) -> Option<Event> {
+    parse(data)
+        .map(Event::ConfigInitialized)
+        .or_else(|| parse(data).map(Event::MinterCertIssued))
+        .or_else(|| parse(data).map(Event::QuotaUpdated))
+        .or_else(|| parse(data).map(Event::ConsumerCertIssued))
+        .or_else(|| parse(data).map(Event::RwaTokenCreated))
+        .or_else(|| parse(data).map(Event::CreditsMinted))
+        .or_else(|| parse(data).map(Event::CreditsRetired))
+        .or_else(|| parse(data).map(Event::Transfer))
+}
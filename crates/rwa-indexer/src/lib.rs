@@ -0,0 +1,89 @@
+//! Indexes `rwa-tokenization` and `token-transfer-hook` transactions into SQLite: projects,
+//! mints, minters, consumers, quota changes, issuances, transfers, retirements and compliance
+//! actions.
+
+pub mod decode;
+pub mod source;
+pub mod store;
+
+pub use decode::*;
+pub use source::*;
+pub use store::*;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Result;
+use rwa_tokenization_client::{rwa_tokenization, token_transfer_hook};
+
+#[derive(Debug, Default)]
+pub struct SyncSummary {
+    pub fetched: usize,
+    pub indexed: usize,
+}
+
+// pulls every transaction of both programs newer than the stored cursors, or everything since
+// genesis with `replay`, and indexes them oldest first
+pub fn sync(source: &RpcSource, registry: &mut Registry, replay: bool) -> Result<SyncSummary> {
+    if replay {
+        registry.reset()?;
+    }
+
+    let programs = [
+        (RWA_PROGRAM, rwa_tokenization::ID),
+        (HOOK_PROGRAM, token_transfer_hook::ID),
+    ];
+    let mut signatures = Vec::new();
+    let mut newest = Vec::new();
+    for (program, address) in programs {
+        let found = source.signatures(&address, registry.cursor(program)?.as_deref())?;
+        if let Some((_, signature)) = found.last() {
+            newest.push((program, signature.clone()));
+        }
+        signatures.extend(found);
+    }
+    let signatures = order_signatures(signatures, |slot| source.block_signatures(slot))?;
+
+    let mut summary = SyncSummary::default();
+    for (_, signature) in &signatures {
+        summary.fetched += 1;
+        if registry.is_indexed(signature)? {
+            continue;
+        }
+        if registry.index(&source.transaction(signature)?)? {
+            summary.indexed += 1;
+        }
+    }
+    for (program, signature) in newest {
+        registry.set_cursor(program, &signature)?;
+    }
+    Ok(summary)
+}
+
+// oldest first and each signature once, a transfer shows up under both programs. Neither list
+// orders a slot against the other, so slots with several transactions follow their block.
+pub fn order_signatures(
+    mut signatures: Vec<(u64, String)>,
+    mut block_signatures: impl FnMut(u64) -> Result<Vec<String>>,
+) -> Result<Vec<(u64, String)>> {
+    let mut seen = HashSet::new();
+    signatures.retain(|(_, signature)| seen.insert(signature.clone()));
+
+    let mut per_slot: BTreeMap<u64, usize> = BTreeMap::new();
+    for (slot, _) in &signatures {
+        *per_slot.entry(*slot).or_default() += 1;
+    }
+    let mut positions = HashMap::new();
+    for (slot, _) in per_slot.into_iter().filter(|(_, count)| *count > 1) {
+        for (position, signature) in block_signatures(slot)?.into_iter().enumerate() {
+            positions.insert(signature, position);
+        }
+    }
+    signatures.sort_by_cached_key(|(slot, signature)| {
+        (
+            *slot,
+            positions.get(signature).copied().unwrap_or(usize::MAX),
+            signature.clone(),
+        )
+    });
+    Ok(signatures)
+}
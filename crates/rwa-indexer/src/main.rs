@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use rwa_indexer::{sync, Registry, RpcSource};
+
+#[derive(Parser)]
+#[command(
+    name = "rwa-indexer",
+    about = "Index rwa-tokenization transactions into SQLite"
+)]
+struct Cli {
+    #[arg(long, default_value = "http://127.0.0.1:8899")]
+    url: String,
+    #[arg(long, default_value = "rwa-index.sqlite")]
+    db: PathBuf,
+    /// wipe the database and index every transaction since genesis
+    #[arg(long)]
+    replay: bool,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let source = RpcSource::new(cli.url);
+    let mut registry = Registry::open(&cli.db)?;
+    let summary = sync(&source, &mut registry, cli.replay)?;
+    println!(
+        "indexed {} of {} transactions into {}",
+        summary.indexed,
+        summary.fetched,
+        cli.db.display()
+    );
+    Ok(())
+}
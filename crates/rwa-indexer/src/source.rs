@@ -0,0 +1,155 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context as _, Result};
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::{RpcBlockConfig, RpcTransactionConfig},
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status_client_types::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+    TransactionDetails, UiInstruction, UiTransactionEncoding,
+};
+
+use crate::decode::{RawInstruction, RawTransaction};
+
+// anything answering getSignaturesForAddress, getTransaction and getBlock: a local validator or
+// a JSON-RPC compatible stand-in
+pub struct RpcSource {
+    client: RpcClient,
+}
+
+impl RpcSource {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+        }
+    }
+
+    // signatures touching `address` after `until` (or since genesis), oldest first
+    pub fn signatures(&self, address: &Pubkey, until: Option<&str>) -> Result<Vec<(u64, String)>> {
+        let until = until.map(Signature::from_str).transpose()?;
+        let mut signatures = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self.client.get_signatures_for_address_with_config(
+                address,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until,
+                    limit: None,
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(Signature::from_str(&last.signature)?);
+            signatures.extend(
+                page.into_iter()
+                    .map(|status| (status.slot, status.signature)),
+            );
+        }
+        signatures.reverse();
+        Ok(signatures)
+    }
+
+    // signatures of the block at `slot`, in the order the block executed them
+    pub fn block_signatures(&self, slot: u64) -> Result<Vec<String>> {
+        let block = self.client.get_block_with_config(
+            slot,
+            RpcBlockConfig {
+                encoding: None,
+                transaction_details: Some(TransactionDetails::Signatures),
+                rewards: Some(false),
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: Some(0),
+            },
+        )?;
+        block
+            .signatures
+            .with_context(|| format!("block {slot} came without signatures"))
+    }
+
+    pub fn transaction(&self, signature: &str) -> Result<RawTransaction> {
+        let encoded = self.client.get_transaction_with_config(
+            &Signature::from_str(signature)?,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: Some(0),
+            },
+        )?;
+        raw_transaction(signature, encoded)
+    }
+}
+
+// flattens outer and inner instructions into execution order, resolving program ids through
+// the static keys and any address lookup tables
+pub fn raw_transaction(
+    signature: &str,
+    encoded: EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<RawTransaction> {
+    let transaction = encoded
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| anyhow!("transaction {signature} is not binary encoded"))?;
+    let meta = encoded
+        .transaction
+        .meta
+        .ok_or_else(|| anyhow!("transaction {signature} has no status meta"))?;
+
+    let mut keys = transaction.message.static_account_keys().to_vec();
+    if let OptionSerializer::Some(loaded) = meta.loaded_addresses {
+        for key in loaded.writable.iter().chain(&loaded.readonly) {
+            keys.push(Pubkey::from_str(key)?);
+        }
+    }
+    let key = |index: u8| {
+        keys.get(index as usize)
+            .copied()
+            .with_context(|| format!("transaction {signature} has no account {index}"))
+    };
+
+    let inner = match meta.inner_instructions {
+        OptionSerializer::Some(inner) => inner,
+        _ => Vec::new(),
+    };
+    let mut instructions = Vec::new();
+    for (index, outer) in transaction.message.instructions().iter().enumerate() {
+        instructions.push(RawInstruction {
+            program_id: key(outer.program_id_index)?,
+            accounts: outer
+                .accounts
+                .iter()
+                .map(|&index| key(index))
+                .collect::<Result<_>>()?,
+            data: outer.data.clone(),
+        });
+        for group in inner.iter().filter(|group| group.index as usize == index) {
+            for instruction in &group.instructions {
+                let UiInstruction::Compiled(instruction) = instruction else {
+                    continue;
+                };
+                instructions.push(RawInstruction {
+                    program_id: key(instruction.program_id_index)?,
+                    accounts: instruction
+                        .accounts
+                        .iter()
+                        .map(|&index| key(index))
+                        .collect::<Result<_>>()?,
+                    data: bs58::decode(&instruction.data).into_vec()?,
+                });
+            }
+        }
+    }
+
+    Ok(RawTransaction {
+        signature: signature.to_string(),
+        slot: encoded.slot,
+        block_time: encoded.block_time,
+        succeeded: meta.err.is_none(),
+        instructions,
+    })
+}
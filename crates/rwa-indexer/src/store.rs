@@ -0,0 +1,572 @@
+use std::path::Path;
+
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use rwa_tokenization_client::token_transfer_hook::{InvestorClass, TransferDecision};
+
+use crate::decode::{decode, Action, Decoded, Event, RawTransaction};
+
+// u64 amounts, and the u128 totals summing them, are stored as decimal text, sqlite integers
+// are signed 64-bit
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    succeeded INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS instructions (
+    signature TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    program TEXT NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (signature, position)
+);
+CREATE TABLE IF NOT EXISTS projects (
+    mint TEXT PRIMARY KEY,
+    authority TEXT NOT NULL,
+    name TEXT NOT NULL,
+    symbol TEXT NOT NULL,
+    decimals INTEGER NOT NULL,
+    has_transfer_hook INTEGER NOT NULL,
+    has_fee INTEGER NOT NULL,
+    default_frozen INTEGER NOT NULL,
+    has_clawback INTEGER NOT NULL,
+    issued_amount TEXT NOT NULL DEFAULT '0',
+    retired_amount TEXT NOT NULL DEFAULT '0',
+    force_burned_amount TEXT NOT NULL DEFAULT '0',
+    closed_slot INTEGER,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS mints (
+    address TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,
+    rwa_mint TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS minters (
+    nft_mint TEXT PRIMARY KEY,
+    rwa_mint TEXT NOT NULL,
+    minter TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS consumers (
+    nft_mint TEXT PRIMARY KEY,
+    rwa_mint TEXT NOT NULL,
+    consumer TEXT NOT NULL,
+    issued_by TEXT NOT NULL,
+    country_code TEXT NOT NULL,
+    investor_class TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS quota_changes (
+    signature TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    rwa_mint TEXT NOT NULL,
+    minter TEXT NOT NULL,
+    nft_mint TEXT NOT NULL,
+    available_credits TEXT NOT NULL,
+    PRIMARY KEY (signature, position)
+);
+CREATE TABLE IF NOT EXISTS issuances (
+    signature TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    rwa_mint TEXT NOT NULL,
+    minter TEXT NOT NULL,
+    receiver TEXT NOT NULL,
+    credits TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    lockup_until INTEGER,
+    PRIMARY KEY (signature, position)
+);
+CREATE TABLE IF NOT EXISTS transfers (
+    signature TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    mint TEXT NOT NULL,
+    source_token TEXT NOT NULL,
+    source_owner TEXT NOT NULL,
+    destination_token TEXT NOT NULL,
+    destination_owner TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    fee_withheld TEXT NOT NULL,
+    decision TEXT NOT NULL,
+    PRIMARY KEY (signature, position)
+);
+CREATE TABLE IF NOT EXISTS retirements (
+    certificate_mint TEXT PRIMARY KEY,
+    rwa_mint TEXT NOT NULL,
+    consumer TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS compliance_actions (
+    signature TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    rwa_mint TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    action_id TEXT NOT NULL,
+    source_token TEXT NOT NULL,
+    destination_token TEXT,
+    amount TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    document_hash TEXT NOT NULL,
+    PRIMARY KEY (signature, position)
+);
+CREATE TABLE IF NOT EXISTS cursors (
+    program TEXT PRIMARY KEY,
+    signature TEXT NOT NULL
+);
+";
+
+const TABLES: &[&str] = &[
+    "transactions",
+    "instructions",
+    "projects",
+    "mints",
+    "minters",
+    "consumers",
+    "quota_changes",
+    "issuances",
+    "transfers",
+    "retirements",
+    "compliance_actions",
+    "cursors",
+];
+
+pub struct Registry {
+    connection: Connection,
+}
+
+impl Registry {
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    // drops everything indexed so far, for a replay from genesis
+    pub fn reset(&mut self) -> Result<()> {
+        let tx = self.connection.transaction()?;
+        for table in TABLES {
+            tx.execute(&format!("DELETE FROM {table}"), [])?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn cursor(&self, program: &str) -> Result<Option<String>> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT signature FROM cursors WHERE program = ?1",
+                [program],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    pub fn set_cursor(&self, program: &str, signature: &str) -> Result<()> {
+        self.connection.execute(
+            "INSERT OR REPLACE INTO cursors (program, signature) VALUES (?1, ?2)",
+            [program, signature],
+        )?;
+        Ok(())
+    }
+
+    pub fn is_indexed(&self, signature: &str) -> Result<bool> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT 1 FROM transactions WHERE signature = ?1",
+                [signature],
+                |_| Ok(()),
+            )
+            .optional()?
+            .is_some())
+    }
+
+    // records the transaction and everything decoded from it; returns false if it was already
+    // indexed. Failed transactions are recorded without their instructions, which had no effect.
+    pub fn index(&mut self, transaction: &RawTransaction) -> Result<bool> {
+        if self.is_indexed(&transaction.signature)? {
+            return Ok(false);
+        }
+        let tx = self.connection.transaction()?;
+        tx.execute(
+            "INSERT INTO transactions (signature, slot, block_time, succeeded) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                transaction.signature,
+                transaction.slot,
+                transaction.block_time,
+                transaction.succeeded
+            ],
+        )?;
+        if transaction.succeeded {
+            for (position, instruction) in transaction.instructions.iter().enumerate() {
+                match decode(instruction) {
+                    Some(Decoded::Instruction {
+                        program,
+                        name,
+                        action,
+                    }) => {
+                        tx.execute(
+                            "INSERT INTO instructions (signature, position, program, name) VALUES (?1, ?2, ?3, ?4)",
+                            params![transaction.signature, position, program, name],
+                        )?;
+                        if let Some(action) = action {
+                            apply_action(&tx, transaction, position, &action)?;
+                        }
+                    }
+                    Some(Decoded::Event(event)) => {
+                        insert_event(&tx, transaction, position, &event)?;
+                    }
+                    None => {}
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(true)
+    }
+}
+
+fn insert_event(
+    tx: &Transaction,
+    transaction: &RawTransaction,
+    position: usize,
+    event: &Event,
+) -> Result<()> {
+    let signature = &transaction.signature;
+    let slot = transaction.slot;
+    match event {
+        Event::ConfigInitialized(_) => {}
+        Event::RwaTokenCreated(event) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO projects (mint, authority, name, symbol, decimals, has_transfer_hook, has_fee, default_frozen, has_clawback, slot, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    event.mint.to_string(),
+                    event.authority.to_string(),
+                    event.name,
+                    event.symbol,
+                    event.decimals,
+                    event.has_transfer_hook,
+                    event.has_fee,
+                    event.default_frozen,
+                    event.has_clawback,
+                    slot,
+                    signature
+                ],
+            )?;
+            insert_mint(
+                tx,
+                "rwa_token",
+                &event.mint.to_string(),
+                &event.mint.to_string(),
+                transaction,
+            )?;
+        }
+        Event::MinterCertIssued(event) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO minters (nft_mint, rwa_mint, minter, slot, signature) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    event.nft_mint.to_string(),
+                    event.rwa_mint.to_string(),
+                    event.minter.to_string(),
+                    slot,
+                    signature
+                ],
+            )?;
+            insert_mint(
+                tx,
+                "minter_cert",
+                &event.nft_mint.to_string(),
+                &event.rwa_mint.to_string(),
+                transaction,
+            )?;
+        }
+        Event::ConsumerCertIssued(event) => {
+            let investor_class = match event.jurisdiction.investor_class {
+                InvestorClass::Retail => "retail",
+                InvestorClass::Professional => "professional",
+                InvestorClass::Institutional => "institutional",
+            };
+            tx.execute(
+                "INSERT OR REPLACE INTO consumers (nft_mint, rwa_mint, consumer, issued_by, country_code, investor_class, slot, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    event.nft_mint.to_string(),
+                    event.rwa_mint.to_string(),
+                    event.consumer.to_string(),
+                    event.issued_by.to_string(),
+                    String::from_utf8_lossy(&event.jurisdiction.country_code),
+                    investor_class,
+                    slot,
+                    signature
+                ],
+            )?;
+            insert_mint(
+                tx,
+                "consumer_cert",
+                &event.nft_mint.to_string(),
+                &event.rwa_mint.to_string(),
+                transaction,
+            )?;
+        }
+        Event::QuotaUpdated(event) => {
+            tx.execute(
+                "INSERT INTO quota_changes (signature, position, slot, rwa_mint, minter, nft_mint, available_credits)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    position,
+                    slot,
+                    event.rwa_mint.to_string(),
+                    event.minter.to_string(),
+                    event.nft_mint.to_string(),
+                    event.available_credits.to_string()
+                ],
+            )?;
+        }
+        Event::CreditsMinted(event) => {
+            tx.execute(
+                "INSERT INTO issuances (signature, position, slot, rwa_mint, minter, receiver, credits, amount, lockup_until)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    signature,
+                    position,
+                    slot,
+                    event.rwa_mint.to_string(),
+                    event.minter.to_string(),
+                    event.receiver.to_string(),
+                    event.credits.to_string(),
+                    event.amount.to_string(),
+                    event.lockup_until
+                ],
+            )?;
+            add_to_total(
+                tx,
+                &event.rwa_mint.to_string(),
+                "issued_amount",
+                event.amount,
+            )?;
+        }
+        Event::CreditsRetired(event) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO retirements (certificate_mint, rwa_mint, consumer, amount, slot, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    event.certificate_mint.to_string(),
+                    event.rwa_mint.to_string(),
+                    event.consumer.to_string(),
+                    event.amount.to_string(),
+                    slot,
+                    signature
+                ],
+            )?;
+            add_to_total(
+                tx,
+                &event.rwa_mint.to_string(),
+                "retired_amount",
+                event.amount,
+            )?;
+            insert_mint(
+                tx,
+                "retirement_cert",
+                &event.certificate_mint.to_string(),
+                &event.rwa_mint.to_string(),
+                transaction,
+            )?;
+        }
+        Event::Transfer(event) => {
+            let decision = match event.decision {
+                TransferDecision::PolicyPassed => "policy_passed",
+                TransferDecision::AllowlistExempt => "allowlist_exempt",
+                TransferDecision::PermanentDelegate => "permanent_delegate",
+            };
+            tx.execute(
+                "INSERT INTO transfers (signature, position, slot, mint, source_token, source_owner, destination_token, destination_owner, amount, fee_withheld, decision)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    signature,
+                    position,
+                    slot,
+                    event.mint.to_string(),
+                    event.source_token.to_string(),
+                    event.source_owner.to_string(),
+                    event.destination_token.to_string(),
+                    event.destination_owner.to_string(),
+                    event.amount.to_string(),
+                    event.fee_withheld.to_string(),
+                    decision
+                ],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn apply_action(
+    tx: &Transaction,
+    transaction: &RawTransaction,
+    position: usize,
+    action: &Action,
+) -> Result<()> {
+    let signature = &transaction.signature;
+    let slot = transaction.slot;
+    match action {
+        Action::MigrateMinterHolder(migration) => {
+            tx.execute(
+                "UPDATE minters SET nft_mint = ?1, minter = ?2, slot = ?3, signature = ?4 WHERE nft_mint = ?5",
+                params![
+                    migration.new_nft_mint.to_string(),
+                    migration.new_wallet.to_string(),
+                    slot,
+                    signature,
+                    migration.old_nft_mint.to_string()
+                ],
+            )?;
+            insert_mint(
+                tx,
+                "minter_cert",
+                &migration.new_nft_mint.to_string(),
+                &migration.rwa_mint.to_string(),
+                transaction,
+            )?;
+        }
+        Action::MigrateConsumerHolder(migration) => {
+            // issuer and jurisdiction carry over to the recovery wallet
+            tx.execute(
+                "UPDATE consumers SET nft_mint = ?1, consumer = ?2, slot = ?3, signature = ?4 WHERE nft_mint = ?5",
+                params![
+                    migration.new_nft_mint.to_string(),
+                    migration.new_wallet.to_string(),
+                    slot,
+                    signature,
+                    migration.old_nft_mint.to_string()
+                ],
+            )?;
+            insert_mint(
+                tx,
+                "consumer_cert",
+                &migration.new_nft_mint.to_string(),
+                &migration.rwa_mint.to_string(),
+                transaction,
+            )?;
+        }
+        Action::ForceTransfer {
+            rwa_mint,
+            source_token,
+            destination_token,
+            args,
+        } => {
+            tx.execute(
+                "INSERT INTO compliance_actions (signature, position, slot, rwa_mint, kind, action_id, source_token, destination_token, amount, reason, document_hash)
+                 VALUES (?1, ?2, ?3, ?4, 'force_transfer', ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    signature,
+                    position,
+                    slot,
+                    rwa_mint.to_string(),
+                    args.action_id.to_string(),
+                    source_token.to_string(),
+                    destination_token.to_string(),
+                    args.amount.to_string(),
+                    args.reason,
+                    hex(&args.document_hash)
+                ],
+            )?;
+        }
+        Action::ForceBurn {
+            rwa_mint,
+            source_token,
+            args,
+        } => {
+            tx.execute(
+                "INSERT INTO compliance_actions (signature, position, slot, rwa_mint, kind, action_id, source_token, amount, reason, document_hash)
+                 VALUES (?1, ?2, ?3, ?4, 'force_burn', ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    signature,
+                    position,
+                    slot,
+                    rwa_mint.to_string(),
+                    args.action_id.to_string(),
+                    source_token.to_string(),
+                    args.amount.to_string(),
+                    args.reason,
+                    hex(&args.document_hash)
+                ],
+            )?;
+            add_to_total(
+                tx,
+                &rwa_mint.to_string(),
+                "force_burned_amount",
+                args.amount,
+            )?;
+        }
+        Action::CloseRwaToken { rwa_mint } => {
+            tx.execute(
+                "UPDATE projects SET closed_slot = ?1 WHERE mint = ?2",
+                params![slot, rwa_mint.to_string()],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// `column` is one of the projects totals, never caller input
+fn add_to_total(tx: &Transaction, rwa_mint: &str, column: &str, amount: u64) -> Result<()> {
+    let total: Option<String> = tx
+        .query_row(
+            &format!("SELECT {column} FROM projects WHERE mint = ?1"),
+            [rwa_mint],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(total) = total else {
+        return Ok(());
+    };
+    let total = total.parse::<u128>()? + amount as u128;
+    tx.execute(
+        &format!("UPDATE projects SET {column} = ?1 WHERE mint = ?2"),
+        params![total.to_string(), rwa_mint],
+    )?;
+    Ok(())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn insert_mint(
+    tx: &Transaction,
+    kind: &str,
+    address: &str,
+    rwa_mint: &str,
+    transaction: &RawTransaction,
+) -> Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO mints (address, kind, rwa_mint, slot, signature) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![address, kind, rwa_mint, transaction.slot, transaction.signature],
+    )?;
+    Ok(())
+}
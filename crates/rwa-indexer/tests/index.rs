@@ -0,0 +1,581 @@
+// Feeds getTransaction responses, shaped as a JSON-RPC node returns them, through decoding
+// and into the registry.
+
+use anchor_lang::{event::EVENT_IX_TAG_LE, prelude::Pubkey, Event, InstructionData};
+use anyhow::anyhow;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rwa_indexer::{order_signatures, raw_transaction, Registry};
+use rwa_tokenization_client::{
+    associated_token_address, consumer_nft_pda,
+    instructions::{self, TokenAccountRef},
+    minter_nft_pda,
+    rwa_tokenization::{
+        self, instruction, ConsumerCertIssued, CreditsMinted, CreditsRetired, MinterCertIssued,
+        RwaTokenCreated,
+    },
+    token_transfer_hook::{self, InvestorClass, Jurisdiction, TransferDecision, TransferEvent},
+};
+use serde_json::json;
+use solana_sdk::{
+    instruction::Instruction,
+    message::Message,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+fn event_cpi(program_id: Pubkey, event: &impl Event) -> Instruction {
+    Instruction::new_with_bytes(
+        program_id,
+        &[EVENT_IX_TAG_LE, &event.data()].concat(),
+        vec![],
+    )
+}
+
+// one outer instruction and the inner instructions it invoked
+fn get_transaction(
+    slot: u64,
+    outer: Instruction,
+    inner: &[Instruction],
+    failed: bool,
+) -> serde_json::Value {
+    let payer = Keypair::new();
+    let mut instructions = vec![outer];
+    instructions.extend_from_slice(inner);
+    let message = Message::new(&instructions, Some(&payer.pubkey()));
+    let index = |address: &Pubkey| {
+        message
+            .account_keys
+            .iter()
+            .position(|key| key == address)
+            .unwrap()
+    };
+    let inner: Vec<_> = inner
+        .iter()
+        .map(|ix| {
+            let accounts: Vec<_> = ix.accounts.iter().map(|meta| index(&meta.pubkey)).collect();
+            json!({
+                "programIdIndex": index(&ix.program_id),
+                "accounts": accounts,
+                "data": bs58(&ix.data),
+                "stackHeight": 2,
+            })
+        })
+        .collect();
+    let mut transaction = Transaction::new_unsigned(message.clone());
+    transaction.message.instructions.truncate(1);
+    // the other signers of the message only need a signature slot to decode
+    transaction.signatures =
+        vec![Signature::default(); message.header.num_required_signatures as usize];
+    transaction.signatures[0] = payer.sign_message(&message.serialize());
+    let bytes = bincode::serialize(&VersionedTransaction::from(transaction)).unwrap();
+    let err = failed.then(|| json!({ "InstructionError": [0, { "Custom": 6000 }] }));
+    json!({
+        "slot": slot,
+        "blockTime": 1_700_000_000 + slot as i64,
+        "transaction": [STANDARD.encode(bytes), "base64"],
+        "meta": {
+            "err": err,
+            "status": match &err { Some(err) => json!({ "Err": err }), None => json!({ "Ok": null }) },
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "innerInstructions": [{ "index": 0, "instructions": inner }],
+        },
+    })
+}
+
+fn bs58(data: &[u8]) -> String {
+    solana_sdk::bs58::encode(data).into_string()
+}
+
+fn index(registry: &mut Registry, signature: &str, response: serde_json::Value) -> bool {
+    let encoded = serde_json::from_value(response).unwrap();
+    registry
+        .index(&raw_transaction(signature, encoded).unwrap())
+        .unwrap()
+}
+
+fn created(rwa_mint: Pubkey) -> RwaTokenCreated {
+    RwaTokenCreated {
+        mint: rwa_mint,
+        authority: Pubkey::new_unique(),
+        name: "Carbon".into(),
+        symbol: "CO2".into(),
+        decimals: 6,
+        has_transfer_hook: true,
+        has_fee: false,
+        default_frozen: false,
+        has_clawback: true,
+    }
+}
+
+fn total(registry: &Registry, rwa_mint: &Pubkey, column: &str) -> String {
+    registry
+        .connection()
+        .query_row(
+            &format!("SELECT {column} FROM projects WHERE mint = ?1"),
+            [rwa_mint.to_string()],
+            |row| row.get(0),
+        )
+        .unwrap()
+}
+
+fn count(registry: &Registry, table: &str) -> i64 {
+    registry
+        .connection()
+        .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+            row.get(0)
+        })
+        .unwrap()
+}
+
+#[test]
+fn indexes_issuance_transfer_and_retirement() {
+    let mut registry = Registry::open_in_memory().unwrap();
+    let rwa_mint = Pubkey::new_unique();
+    let minter = Pubkey::new_unique();
+    let alice = Pubkey::new_unique();
+    let bob = Pubkey::new_unique();
+
+    let create = Instruction::new_with_bytes(
+        rwa_tokenization::ID,
+        &instruction::InitRwaToken {
+            name: "Carbon".into(),
+            symbol: "CO2".into(),
+            decimals: 6,
+            uri: String::new(),
+            is_close: true,
+            has_fee: false,
+            transfer_fee_basis_points: None,
+            maximum_fee: None,
+            default_frozen: false,
+            has_clawback: true,
+        }
+        .data(),
+        vec![],
+    );
+    let created = created(rwa_mint);
+    let minted = CreditsMinted {
+        rwa_mint,
+        minter,
+        receiver: alice,
+        credits: 10,
+        amount: 10_000_000,
+        lockup_until: None,
+    };
+    assert!(index(
+        &mut registry,
+        "create",
+        get_transaction(
+            1,
+            create,
+            &[
+                event_cpi(rwa_tokenization::ID, &created),
+                event_cpi(rwa_tokenization::ID, &minted),
+            ],
+            false,
+        ),
+    ));
+
+    // token-2022 invokes the hook, which emits its transfer event
+    let transfer = TransferEvent {
+        mint: rwa_mint,
+        source_token: Pubkey::new_unique(),
+        source_owner: alice,
+        destination_token: Pubkey::new_unique(),
+        destination_owner: bob,
+        amount: 4_000_000,
+        fee_withheld: 0,
+        slot: 2,
+        decision: TransferDecision::PolicyPassed,
+    };
+    let token_transfer = Instruction::new_with_bytes(Pubkey::new_unique(), &[12], vec![]);
+    let execute = Instruction::new_with_bytes(
+        token_transfer_hook::ID,
+        &token_transfer_hook::instruction::Execute { amount: 4_000_000 }.data(),
+        vec![],
+    );
+    index(
+        &mut registry,
+        "transfer",
+        get_transaction(
+            2,
+            token_transfer,
+            &[execute, event_cpi(token_transfer_hook::ID, &transfer)],
+            false,
+        ),
+    );
+
+    let certificate_mint = Pubkey::new_unique();
+    let retire = Instruction::new_with_bytes(
+        rwa_tokenization::ID,
+        &instruction::RetireToken {
+            amount: u64::MAX,
+            retirement_id: 0,
+        }
+        .data(),
+        vec![],
+    );
+    let retired = CreditsRetired {
+        rwa_mint,
+        consumer: bob,
+        amount: u64::MAX,
+        certificate_mint,
+    };
+    index(
+        &mut registry,
+        "retire",
+        get_transaction(
+            3,
+            retire.clone(),
+            &[event_cpi(rwa_tokenization::ID, &retired)],
+            false,
+        ),
+    );
+    // a failed transaction is recorded but nothing it emitted counts
+    index(
+        &mut registry,
+        "failed",
+        get_transaction(
+            4,
+            retire,
+            &[event_cpi(rwa_tokenization::ID, &retired)],
+            true,
+        ),
+    );
+
+    let connection = registry.connection();
+    let (symbol, decimals): (String, u8) = connection
+        .query_row(
+            "SELECT symbol, decimals FROM projects WHERE mint = ?1",
+            [rwa_mint.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!((symbol.as_str(), decimals), ("CO2", 6));
+    let (receiver, amount): (String, String) = connection
+        .query_row("SELECT receiver, amount FROM issuances", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(
+        (receiver, amount),
+        (alice.to_string(), "10000000".to_string())
+    );
+    let (destination, decision): (String, String) = connection
+        .query_row(
+            "SELECT destination_owner, decision FROM transfers",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(
+        (destination, decision),
+        (bob.to_string(), "policy_passed".to_string())
+    );
+    let amount: String = connection
+        .query_row(
+            "SELECT amount FROM retirements WHERE certificate_mint = ?1",
+            [certificate_mint.to_string()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(amount, u64::MAX.to_string());
+    let names: Vec<String> = connection
+        .prepare("SELECT name FROM instructions ORDER BY signature, position")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(names, ["init_rwa_token", "retire_token", "execute"]);
+    assert_eq!(count(&registry, "transactions"), 4);
+    assert_eq!(count(&registry, "retirements"), 1);
+    assert_eq!(count(&registry, "mints"), 2);
+    assert_eq!(total(&registry, &rwa_mint, "issued_amount"), "10000000");
+    assert_eq!(
+        total(&registry, &rwa_mint, "retired_amount"),
+        u64::MAX.to_string()
+    );
+
+    // indexing is idempotent and a replay starts from an empty registry
+    assert!(!index(
+        &mut registry,
+        "create",
+        get_transaction(
+            1,
+            Instruction::new_with_bytes(rwa_tokenization::ID, &[], vec![]),
+            &[],
+            false
+        ),
+    ));
+    registry.set_cursor("rwa-tokenization", "retire").unwrap();
+    registry.reset().unwrap();
+    assert_eq!(count(&registry, "transactions"), 0);
+    assert_eq!(registry.cursor("rwa-tokenization").unwrap(), None);
+}
+
+#[test]
+fn indexes_migrations_forced_actions_and_close() {
+    let mut registry = Registry::open_in_memory().unwrap();
+    let rwa_mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let (minter, new_minter) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (consumer, new_consumer) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let unit = |name| Instruction::new_with_bytes(rwa_tokenization::ID, name, vec![]);
+
+    let issued = [
+        event_cpi(rwa_tokenization::ID, &created(rwa_mint)),
+        event_cpi(
+            rwa_tokenization::ID,
+            &MinterCertIssued {
+                rwa_mint,
+                minter,
+                nft_mint: minter_nft_pda(&rwa_mint, &minter),
+            },
+        ),
+        event_cpi(
+            rwa_tokenization::ID,
+            &ConsumerCertIssued {
+                rwa_mint,
+                consumer,
+                nft_mint: consumer_nft_pda(&rwa_mint, &consumer),
+                issued_by: minter,
+                jurisdiction: Jurisdiction {
+                    country_code: *b"DE",
+                    investor_class: InvestorClass::Professional,
+                },
+            },
+        ),
+        event_cpi(
+            rwa_tokenization::ID,
+            &CreditsMinted {
+                rwa_mint,
+                minter,
+                receiver: consumer,
+                credits: 10,
+                amount: 10_000_000,
+                lockup_until: None,
+            },
+        ),
+    ];
+    index(
+        &mut registry,
+        "issue",
+        get_transaction(1, unit(&[]), &issued, false),
+    );
+
+    // the instructions carry no event, so the registry reads them from their accounts
+    index(
+        &mut registry,
+        "migrate minter",
+        get_transaction(
+            2,
+            instructions::migrate_minter_holder(
+                &authority,
+                &rwa_mint,
+                &minter,
+                &new_minter,
+                false,
+                true,
+                false,
+            ),
+            &[],
+            false,
+        ),
+    );
+    index(
+        &mut registry,
+        "migrate consumer",
+        get_transaction(
+            2,
+            instructions::migrate_consumer_holder(
+                &authority,
+                &rwa_mint,
+                &consumer,
+                &new_consumer,
+                true,
+                true,
+                false,
+            ),
+            &[],
+            false,
+        ),
+    );
+    let source = TokenAccountRef {
+        address: associated_token_address(&new_consumer, &rwa_mint),
+        owner: new_consumer,
+    };
+    let destination = TokenAccountRef {
+        address: associated_token_address(&authority, &rwa_mint),
+        owner: authority,
+    };
+    index(
+        &mut registry,
+        "force",
+        get_transaction(
+            3,
+            instructions::force_transfer(
+                &authority,
+                &rwa_mint,
+                &source,
+                &destination,
+                true,
+                instruction::ForceTransfer {
+                    action_id: 7,
+                    amount: 3_000_000,
+                    reason: "court order".into(),
+                    document_hash: [0xab; 32],
+                },
+            ),
+            &[instructions::force_burn(
+                &authority,
+                &rwa_mint,
+                &source.address,
+                instruction::ForceBurn {
+                    action_id: 8,
+                    amount: 2_000_000,
+                    reason: "sanctions".into(),
+                    document_hash: [0x01; 32],
+                },
+            )],
+            false,
+        ),
+    );
+    // a failed close leaves the project open
+    let close = instructions::close_rwa_token(&authority, &rwa_mint, true);
+    index(
+        &mut registry,
+        "failed close",
+        get_transaction(4, close.clone(), &[], true),
+    );
+    assert!(registry
+        .connection()
+        .query_row("SELECT closed_slot FROM projects", [], |row| row
+            .get::<_, Option<u64>>(0))
+        .unwrap()
+        .is_none());
+    index(
+        &mut registry,
+        "close",
+        get_transaction(5, close, &[], false),
+    );
+
+    let connection = registry.connection();
+    let (nft_mint, wallet): (String, String) = connection
+        .query_row("SELECT nft_mint, minter FROM minters", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(
+        (nft_mint, wallet),
+        (
+            minter_nft_pda(&rwa_mint, &new_minter).to_string(),
+            new_minter.to_string()
+        )
+    );
+    let (nft_mint, wallet, country_code): (String, String, String) = connection
+        .query_row(
+            "SELECT nft_mint, consumer, country_code FROM consumers",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(
+        (nft_mint, wallet, country_code),
+        (
+            consumer_nft_pda(&rwa_mint, &new_consumer).to_string(),
+            new_consumer.to_string(),
+            "DE".to_string()
+        )
+    );
+    // the new certificates are known mints, the old ones stay on record
+    assert_eq!(count(&registry, "mints"), 5);
+
+    let actions: Vec<(String, String, String, Option<String>, String, String)> = connection
+        .prepare(
+            "SELECT kind, action_id, source_token, destination_token, amount, document_hash
+             FROM compliance_actions ORDER BY slot, position",
+        )
+        .unwrap()
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get(5)?,
+            ))
+        })
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        actions,
+        [
+            (
+                "force_transfer".to_string(),
+                "7".to_string(),
+                source.address.to_string(),
+                Some(destination.address.to_string()),
+                "3000000".to_string(),
+                "ab".repeat(32)
+            ),
+            (
+                "force_burn".to_string(),
+                "8".to_string(),
+                source.address.to_string(),
+                None,
+                "2000000".to_string(),
+                "01".repeat(32)
+            ),
+        ]
+    );
+    assert_eq!(total(&registry, &rwa_mint, "issued_amount"), "10000000");
+    assert_eq!(total(&registry, &rwa_mint, "retired_amount"), "0");
+    assert_eq!(
+        total(&registry, &rwa_mint, "force_burned_amount"),
+        "2000000"
+    );
+    let closed_slot: Option<u64> = connection
+        .query_row("SELECT closed_slot FROM projects", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(closed_slot, Some(5));
+}
+
+#[test]
+fn transactions_of_a_slot_follow_the_block() {
+    let signatures = vec![
+        (7, "hook transfer".to_string()),
+        (5, "alone".to_string()),
+        (7, "retire".to_string()),
+        (7, "mint".to_string()),
+        (7, "hook transfer".to_string()),
+    ];
+    let mut fetched = Vec::new();
+    let ordered = order_signatures(signatures, |slot| {
+        fetched.push(slot);
+        Ok(["vote", "mint", "hook transfer", "retire"]
+            .map(String::from)
+            .to_vec())
+    })
+    .unwrap();
+    assert_eq!(
+        ordered,
+        [
+            (5, "alone".to_string()),
+            (7, "mint".to_string()),
+            (7, "hook transfer".to_string()),
+            (7, "retire".to_string()),
+        ]
+    );
+    // a slot with a single transaction needs no block
+    assert_eq!(fetched, [7]);
+
+    let failed = order_signatures(vec![(1, "a".into()), (1, "b".into())], |_| {
+        Err(anyhow!("block unavailable"))
+    });
+    assert!(failed.is_err());
+}
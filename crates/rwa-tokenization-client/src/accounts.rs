@@ -0,0 +1,53 @@
+use anchor_lang::{AccountDeserialize, Result};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use rwa_tokenization::{
+    ComplianceAction, ConsumerController, GovernanceConfig, MintAuthority, MinterController,
+};
+
+/// Offset of the metadata pointer authority in a retirement certificate mint. Only retirement
+/// certificates have the `MintAuthority` PDA there, so a memcmp on it finds those of one RWA mint.
+pub const RETIREMENT_CERT_AUTHORITY_OFFSET: usize = 170;
+
+/// Decode any rwa-tokenization or token-transfer-hook account, checking its discriminator.
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn governance_config(data: &[u8]) -> Result<GovernanceConfig> {
+    decode_account(data)
+}
+
+pub fn mint_authority(data: &[u8]) -> Result<MintAuthority> {
+    decode_account(data)
+}
+
+pub fn minter_controller(data: &[u8]) -> Result<MinterController> {
+    decode_account(data)
+}
+
+pub fn consumer_controller(data: &[u8]) -> Result<ConsumerController> {
+    decode_account(data)
+}
+
+pub fn compliance_action(data: &[u8]) -> Result<ComplianceAction> {
+    decode_account(data)
+}
+
+/// Token-2022 metadata embedded in an RWA mint or a certificate nft mint.
+pub fn token_metadata(mint_data: &[u8]) -> Result<TokenMetadata> {
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    Ok(mint.get_variable_len_extension::<TokenMetadata>()?)
+}
+
+/// Credit counter (`available_credits`, `minted_credits`, `retired_credits`) of a certificate.
+pub fn credits(metadata: &TokenMetadata, key: &str) -> Option<u64> {
+    metadata
+        .additional_metadata
+        .iter()
+        .find(|(field, _)| field == key)
+        .and_then(|(_, value)| value.parse().ok())
+}
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        extension::{
+            metadata_pointer::MetadataPointer, mint_close_authority::MintCloseAuthority,
+            permanent_delegate::PermanentDelegate, BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Mint,
+    },
+};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use rwa_tokenization::{
+    RETIRED_BY_KEY, RETIRED_CREDITS_CERT_NAME, RETIRED_CREDITS_CERT_SYMBOL, RETIRED_CREDITS_KEY,
+    RETIREMENT_ID_KEY,
+};
+
+use crate::{credits, mint_authority_pda, retirement_certificate_pda};
+
+/// Owner and data of an account, as fetched by the caller.
+pub struct FetchedAccount {
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+pub struct CertificateCheck {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+pub struct CertificateVerification {
+    pub certificate_mint: Pubkey,
+    /// RWA mint whose `MintAuthority` PDA must have issued the certificate.
+    pub rwa_mint: Pubkey,
+    pub retired_credits: Option<u64>,
+    /// Consumer whose retirement the certificate records, once its address is established.
+    pub retired_by: Option<Pubkey>,
+    pub checks: Vec<CertificateCheck>,
+}
+
+impl CertificateVerification {
+    pub fn is_genuine(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    fn check(&mut self, name: &'static str, passed: bool, detail: impl Into<String>) -> bool {
+        self.checks.push(CertificateCheck {
+            name,
+            passed,
+            detail: detail.into(),
+        });
+        passed
+    }
+}
+
+/// Check that `certificate_mint` was created by `retire_token` of `rwa_mint`: the certificate
+/// PDA of the consumer and retirement id in its metadata, a Token-2022 mint with supply 1 and no
+/// mint authority, whose metadata carries `retired_credits` and whose authorities are the
+/// `MintAuthority` PDA of `rwa_mint`. Only the certificate itself is fetched, so certificates of
+/// closed mints still verify. `fetch` returns the account at an address, or `None` if it does
+/// not exist.
+pub fn verify_retirement_certificate(
+    certificate_mint: &Pubkey,
+    rwa_mint: &Pubkey,
+    mut fetch: impl FnMut(&Pubkey) -> Option<FetchedAccount>,
+) -> CertificateVerification {
+    let mut verification = CertificateVerification {
+        certificate_mint: *certificate_mint,
+        rwa_mint: *rwa_mint,
+        retired_credits: None,
+        retired_by: None,
+        checks: Vec::new(),
+    };
+
+    let Some(account) = fetch(certificate_mint) else {
+        verification.check("exists", false, "certificate mint not found");
+        return verification;
+    };
+    if !verification.check(
+        "token_program",
+        account.owner == token_2022::ID,
+        format!("owned by {}", account.owner),
+    ) {
+        return verification;
+    }
+    let Ok(mint) = StateWithExtensions::<Mint>::unpack(&account.data) else {
+        verification.check("mint", false, "not a Token-2022 mint");
+        return verification;
+    };
+
+    verification.check(
+        "supply",
+        mint.base.supply == 1 && mint.base.decimals == 0,
+        format!(
+            "supply {} with {} decimals",
+            mint.base.supply, mint.base.decimals
+        ),
+    );
+    verification.check(
+        "mint_authority_removed",
+        mint.base.mint_authority.is_none(),
+        match Option::<Pubkey>::from(mint.base.mint_authority) {
+            Some(authority) => format!("mint authority is still {authority}"),
+            None => "no mint authority".to_string(),
+        },
+    );
+
+    let Ok(metadata) = mint.get_variable_len_extension::<TokenMetadata>() else {
+        verification.check("metadata", false, "no token metadata");
+        return verification;
+    };
+    verification.check(
+        "metadata",
+        metadata.name == RETIRED_CREDITS_CERT_NAME
+            && metadata.symbol == RETIRED_CREDITS_CERT_SYMBOL,
+        format!("{} ({})", metadata.name, metadata.symbol),
+    );
+    verification.retired_credits = credits(&metadata, RETIRED_CREDITS_KEY);
+    verification.check(
+        "retired_credits",
+        verification.retired_credits.is_some(),
+        match verification.retired_credits {
+            Some(amount) => format!("{amount} base units retired"),
+            None => format!("no numeric {RETIRED_CREDITS_KEY} key"),
+        },
+    );
+
+    // only retire_token can create an account at the certificate PDA, anyone can copy the rest
+    let retired_by = field(&metadata, RETIRED_BY_KEY).and_then(|value| value.parse().ok());
+    let retirement_id = field(&metadata, RETIREMENT_ID_KEY).and_then(|value| value.parse().ok());
+    let derived = retired_by
+        .zip(retirement_id)
+        .map(|(consumer, id)| retirement_certificate_pda(rwa_mint, &consumer, id));
+    if verification.check(
+        "address",
+        derived == Some(*certificate_mint),
+        match (retired_by, retirement_id) {
+            (Some(consumer), Some(id)) => format!("retirement {id} of {consumer}"),
+            _ => format!("no {RETIRED_BY_KEY} and {RETIREMENT_ID_KEY} keys"),
+        },
+    ) {
+        verification.retired_by = retired_by;
+    }
+
+    let authority = mint_authority_pda(rwa_mint);
+    let update_authority = Option::<Pubkey>::from(metadata.update_authority);
+    verification.check(
+        "update_authority",
+        update_authority == Some(authority),
+        match update_authority {
+            Some(update_authority) => format!("update authority is {update_authority}"),
+            None => "metadata has no update authority".to_string(),
+        },
+    );
+
+    let pointer = mint.get_extension::<MetadataPointer>().ok();
+    let close = mint.get_extension::<MintCloseAuthority>().ok();
+    let delegate = mint.get_extension::<PermanentDelegate>().ok();
+    let authorities = [
+        pointer.and_then(|pointer| Option::<Pubkey>::from(pointer.authority)),
+        close.and_then(|close| Option::<Pubkey>::from(close.close_authority)),
+        delegate.and_then(|delegate| Option::<Pubkey>::from(delegate.delegate)),
+    ];
+    verification.check(
+        "extension_authorities",
+        authorities == [Some(authority); 3]
+            && pointer.and_then(|pointer| Option::<Pubkey>::from(pointer.metadata_address))
+                == Some(*certificate_mint),
+        "metadata pointer, close authority and permanent delegate",
+    );
+
+    verification
+}
+
+fn field<'a>(metadata: &'a TokenMetadata, key: &str) -> Option<&'a str> {
+    metadata
+        .additional_metadata
+        .iter()
+        .find(|(field, _)| field == key)
+        .map(|(_, value)| value.as_str())
+}
@@ -0,0 +1,673 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    system_program, InstructionData, ToAccountMetas,
+};
+use anchor_spl::{associated_token, token_2022};
+use rwa_tokenization::{accounts, instruction};
+
+use crate::{
+    pda::{
+        account_hold_pda, associated_token_address, compliance_action_pda, compliance_role_pda,
+        config_pda, consumer_controller_pda, consumer_nft_pda, denylist_pda, event_authority_pda,
+        extra_account_meta_list_pda, lockup_pda, mint_authority_pda, minter_controller_pda,
+        minter_nft_pda, retirement_certificate_pda, rwa_mint_pda, transfer_policy_pda,
+    },
+    transfer_hook::{extra_account_metas, HookTransferAccounts},
+};
+
+/// A token account of an RWA mint together with its owner, the hook resolves accounts from both.
+#[derive(Clone, Copy, Debug)]
+pub struct TokenAccountRef {
+    pub address: Pubkey,
+    pub owner: Pubkey,
+}
+
+fn rwa_instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(remaining_accounts);
+    Instruction {
+        program_id: rwa_tokenization::ID,
+        accounts: metas,
+        data: data.data(),
+    }
+}
+
+fn rwa_event_authority() -> Pubkey {
+    event_authority_pda(&rwa_tokenization::ID)
+}
+
+// extra accounts of a clawback transfer, signed by the mint authority as permanent delegate
+fn clawback_extra_accounts(
+    rwa_mint: &Pubkey,
+    source: &TokenAccountRef,
+    destination: &TokenAccountRef,
+    has_transfer_hook: bool,
+) -> Vec<AccountMeta> {
+    if !has_transfer_hook {
+        return vec![];
+    }
+    extra_account_metas(&HookTransferAccounts {
+        source: source.address,
+        mint: *rwa_mint,
+        destination: destination.address,
+        authority: mint_authority_pda(rwa_mint),
+        source_owner: source.owner,
+        destination_owner: destination.owner,
+    })
+}
+
+pub fn initialize(authority: &Pubkey) -> Instruction {
+    rwa_instruction(
+        accounts::InitializeConfig {
+            singer: *authority,
+            config_account: config_pda(),
+            system_program: system_program::ID,
+            event_authority: rwa_event_authority(),
+            program: rwa_tokenization::ID,
+        },
+        instruction::Initialize {},
+        vec![],
+    )
+}
+
+pub fn init_rwa_token(authority: &Pubkey, args: instruction::InitRwaToken) -> Instruction {
+    let mint = rwa_mint_pda(&args.symbol);
+    rwa_instruction(
+        accounts::InitRwaToken {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(&mint),
+            mint,
+            extra_account_meta_list: args.is_close.then(|| extra_account_meta_list_pda(&mint)),
+            transfer_hook_program: token_transfer_hook::ID,
+            rwa_program: rwa_tokenization::ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: rwa_event_authority(),
+            program: rwa_tokenization::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn issue_minter_cert(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    minter: &Pubkey,
+    args: instruction::IssueMinterCert,
+) -> Instruction {
+    let mint = minter_nft_pda(rwa_mint, minter);
+    rwa_instruction(
+        accounts::IssueMinterCert {
+            authority: *authority,
+            config_account: config_pda(),
+            minter_controller: minter_controller_pda(&mint),
+            receiver: *minter,
+            mint,
+            receiver_token_account: associated_token_address(minter, &mint),
+            permissioned_mint: *rwa_mint,
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: token_2022::ID,
+            event_authority: rwa_event_authority(),
+            program: rwa_tokenization::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn update_quota_credit(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    minter: &Pubkey,
+    args: instruction::UpdateQuotaCredit,
+) -> Instruction {
+    let mint = minter_nft_pda(rwa_mint, minter);
+    rwa_instruction(
+        accounts::UpdateQuotaCredit {
+            authority: *authority,
+            minter_controller: minter_controller_pda(&mint),
+            receiver: *minter,
+            mint,
+            permissioned_mint: *rwa_mint,
+            token_program: token_2022::ID,
+            event_authority: rwa_event_authority(),
+            program: rwa_tokenization::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn issue_consumer_cert(
+    payer: &Pubkey,
+    minter: &Pubkey,
+    rwa_mint: &Pubkey,
+    consumer: &Pubkey,
+    args: instruction::IssueConsumerCert,
+) -> Instruction {
+    let mint = consumer_nft_pda(rwa_mint, consumer);
+    rwa_instruction(
+        accounts::IssueConsumerCert {
+            payer: *payer,
+            minter: *minter,
+            receiver: *consumer,
+            minter_nft_mint: minter_nft_pda(rwa_mint, minter),
+            rwa_mint: *rwa_mint,
+            consumer_controller: consumer_controller_pda(&mint),
+            mint,
+            receiver_token_account: associated_token_address(consumer, &mint),
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: token_2022::ID,
+            event_authority: rwa_event_authority(),
+            program: rwa_tokenization::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn mint_rwa_token(
+    payer: &Pubkey,
+    minter: &Pubkey,
+    rwa_mint: &Pubkey,
+    receiver: &Pubkey,
+    args: instruction::MintRwaToken,
+) -> Instruction {
+    let minter_nft_mint = minter_nft_pda(rwa_mint, minter);
+    rwa_instruction(
+        accounts::MintRwaToken {
+            payer: *payer,
+            minter: *minter,
+            receiver: *receiver,
+            minter_controller: minter_controller_pda(&minter_nft_mint),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            receiver_token_account: associated_token_address(receiver, rwa_mint),
+            minter_nft_mint,
+            minter_nft_token_account: associated_token_address(minter, &minter_nft_mint),
+            lockup: args.lockup_until.map(|_| lockup_pda(rwa_mint, receiver)),
+            transfer_policy: args.lockup_until.map(|_| transfer_policy_pda(rwa_mint)),
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: rwa_event_authority(),
+            program: rwa_tokenization::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+/// `args.retirement_id` must be unused for this consumer, it picks the certificate mint.
+pub fn retire_token(
+    payer: &Pubkey,
+    consumer: &Pubkey,
+    rwa_mint: &Pubkey,
+    args: instruction::RetireToken,
+) -> Instruction {
+    let certificate_mint = &retirement_certificate_pda(rwa_mint, consumer, args.retirement_id);
+    rwa_instruction(
+        accounts::RetireToken {
+            payer: *payer,
+            consumer: *consumer,
+            mint_authority: mint_authority_pda(rwa_mint),
+            mint: *rwa_mint,
+            consumer_token_account: associated_token_address(consumer, rwa_mint),
+            nft_mint: *certificate_mint,
+            consumer_nft_token_account: associated_token_address(consumer, certificate_mint),
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: rwa_event_authority(),
+            program: rwa_tokenization::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn thaw_for_consumer(consumer: &Pubkey, rwa_mint: &Pubkey) -> Instruction {
+    let consumer_token_account = associated_token_address(consumer, rwa_mint);
+    let consumer_nft_mint = consumer_nft_pda(rwa_mint, consumer);
+    rwa_instruction(
+        accounts::ThawForConsumer {
+            consumer: *consumer,
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            consumer_token_account,
+            consumer_controller: consumer_controller_pda(&consumer_nft_mint),
+            consumer_nft_mint,
+            consumer_nft_token_account: associated_token_address(consumer, &consumer_nft_mint),
+            account_hold: account_hold_pda(&consumer_token_account),
+            token_program: token_2022::ID,
+        },
+        instruction::ThawForConsumer {},
+        vec![],
+    )
+}
+
+pub fn freeze_account(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    token_account: &Pubkey,
+) -> Instruction {
+    rwa_instruction(
+        accounts::FreezeAccount {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            token_account: *token_account,
+            account_hold: account_hold_pda(token_account),
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::FreezeAccount {},
+        vec![],
+    )
+}
+
+pub fn release_hold(authority: &Pubkey, rwa_mint: &Pubkey, token_account: &Pubkey) -> Instruction {
+    rwa_instruction(
+        accounts::ReleaseHold {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            token_account: *token_account,
+            account_hold: account_hold_pda(token_account),
+            token_program: token_2022::ID,
+        },
+        instruction::ReleaseHold {},
+        vec![],
+    )
+}
+
+pub fn force_transfer(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    source: &TokenAccountRef,
+    destination: &TokenAccountRef,
+    has_transfer_hook: bool,
+    args: instruction::ForceTransfer,
+) -> Instruction {
+    rwa_instruction(
+        accounts::ForceTransfer {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            source_token_account: source.address,
+            destination_token_account: destination.address,
+            compliance_action: compliance_action_pda(rwa_mint, args.action_id),
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        clawback_extra_accounts(rwa_mint, source, destination, has_transfer_hook),
+    )
+}
+
+pub fn force_burn(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    source_token_account: &Pubkey,
+    args: instruction::ForceBurn,
+) -> Instruction {
+    rwa_instruction(
+        accounts::ForceBurn {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            source_token_account: *source_token_account,
+            compliance_action: compliance_action_pda(rwa_mint, args.action_id),
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+/// With `move_rwa_balance` the RWA balance of the old wallet follows to the new one (clawback mints).
+/// `move_lockup` carries the old wallet's Lockup, which must exist, over with the balance.
+pub fn migrate_minter_holder(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    old_wallet: &Pubkey,
+    new_wallet: &Pubkey,
+    move_rwa_balance: bool,
+    has_transfer_hook: bool,
+    move_lockup: bool,
+) -> Instruction {
+    let old_nft_mint = minter_nft_pda(rwa_mint, old_wallet);
+    let new_nft_mint = minter_nft_pda(rwa_mint, new_wallet);
+    let (old_rwa, new_rwa) = holder_token_accounts(rwa_mint, old_wallet, new_wallet);
+    rwa_instruction(
+        accounts::MigrateMinterHolder {
+            authority: *authority,
+            config_account: config_pda(),
+            old_wallet: *old_wallet,
+            new_wallet: *new_wallet,
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            old_minter_controller: minter_controller_pda(&old_nft_mint),
+            old_nft_mint,
+            old_nft_token_account: associated_token_address(old_wallet, &old_nft_mint),
+            new_minter_controller: minter_controller_pda(&new_nft_mint),
+            new_nft_mint,
+            new_nft_token_account: associated_token_address(new_wallet, &new_nft_mint),
+            old_rwa_token_account: move_rwa_balance.then_some(old_rwa.address),
+            new_rwa_token_account: move_rwa_balance.then_some(new_rwa.address),
+            old_lockup: move_lockup.then(|| lockup_pda(rwa_mint, old_wallet)),
+            new_lockup: move_lockup.then(|| lockup_pda(rwa_mint, new_wallet)),
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: token_2022::ID,
+        },
+        instruction::MigrateMinterHolder {},
+        clawback_extra_accounts(
+            rwa_mint,
+            &old_rwa,
+            &new_rwa,
+            move_rwa_balance && has_transfer_hook,
+        ),
+    )
+}
+
+/// With `move_rwa_balance` the RWA balance of the old wallet follows to the new one (clawback mints).
+/// `move_lockup` carries the old wallet's Lockup, which must exist, over with the balance.
+pub fn migrate_consumer_holder(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    old_wallet: &Pubkey,
+    new_wallet: &Pubkey,
+    move_rwa_balance: bool,
+    has_transfer_hook: bool,
+    move_lockup: bool,
+) -> Instruction {
+    let old_nft_mint = consumer_nft_pda(rwa_mint, old_wallet);
+    let new_nft_mint = consumer_nft_pda(rwa_mint, new_wallet);
+    let (old_rwa, new_rwa) = holder_token_accounts(rwa_mint, old_wallet, new_wallet);
+    rwa_instruction(
+        accounts::MigrateConsumerHolder {
+            authority: *authority,
+            config_account: config_pda(),
+            old_wallet: *old_wallet,
+            new_wallet: *new_wallet,
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            old_consumer_controller: consumer_controller_pda(&old_nft_mint),
+            old_nft_mint,
+            old_nft_token_account: associated_token_address(old_wallet, &old_nft_mint),
+            new_consumer_controller: consumer_controller_pda(&new_nft_mint),
+            new_nft_mint,
+            new_nft_token_account: associated_token_address(new_wallet, &new_nft_mint),
+            old_rwa_token_account: move_rwa_balance.then_some(old_rwa.address),
+            new_rwa_token_account: move_rwa_balance.then_some(new_rwa.address),
+            old_lockup: move_lockup.then(|| lockup_pda(rwa_mint, old_wallet)),
+            new_lockup: move_lockup.then(|| lockup_pda(rwa_mint, new_wallet)),
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: token_2022::ID,
+        },
+        instruction::MigrateConsumerHolder {},
+        clawback_extra_accounts(
+            rwa_mint,
+            &old_rwa,
+            &new_rwa,
+            move_rwa_balance && has_transfer_hook,
+        ),
+    )
+}
+
+fn holder_token_accounts(
+    rwa_mint: &Pubkey,
+    old_wallet: &Pubkey,
+    new_wallet: &Pubkey,
+) -> (TokenAccountRef, TokenAccountRef) {
+    (
+        TokenAccountRef {
+            address: associated_token_address(old_wallet, rwa_mint),
+            owner: *old_wallet,
+        },
+        TokenAccountRef {
+            address: associated_token_address(new_wallet, rwa_mint),
+            owner: *new_wallet,
+        },
+    )
+}
+
+pub fn close_rwa_token(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    has_transfer_hook: bool,
+) -> Instruction {
+    rwa_instruction(
+        accounts::CloseRwaToken {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            extra_account_meta_list: has_transfer_hook
+                .then(|| extra_account_meta_list_pda(rwa_mint)),
+            transfer_hook_program: has_transfer_hook.then_some(token_transfer_hook::ID),
+            token_program: token_2022::ID,
+        },
+        instruction::CloseRwaToken {},
+        vec![],
+    )
+}
+
+pub fn update_rwa_metadata(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    args: instruction::UpdateRwaMetadata,
+) -> Instruction {
+    rwa_instruction(
+        accounts::UpdateRwaMetadata {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn set_transfer_policy(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    args: instruction::SetTransferPolicy,
+) -> Instruction {
+    rwa_instruction(
+        accounts::SetTransferPolicy {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            transfer_policy: transfer_policy_pda(rwa_mint),
+            transfer_hook_program: token_transfer_hook::ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn set_allowed_account(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    token_account: &Pubkey,
+    args: instruction::SetAllowedAccount,
+) -> Instruction {
+    rwa_instruction(
+        accounts::SetAllowedAccount {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            token_account: *token_account,
+            transfer_policy: transfer_policy_pda(rwa_mint),
+            transfer_hook_program: token_transfer_hook::ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn set_allowed_program_owner(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    args: instruction::SetAllowedProgramOwner,
+) -> Instruction {
+    rwa_instruction(
+        accounts::SetAllowedProgramOwner {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            transfer_policy: transfer_policy_pda(rwa_mint),
+            transfer_hook_program: token_transfer_hook::ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn set_jurisdiction_rules(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    args: instruction::SetJurisdictionRules,
+) -> Instruction {
+    rwa_instruction(
+        accounts::SetJurisdictionRules {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            transfer_policy: transfer_policy_pda(rwa_mint),
+            transfer_hook_program: token_transfer_hook::ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn grant_compliance_role(authority: &Pubkey, officer: &Pubkey) -> Instruction {
+    rwa_instruction(
+        accounts::GrantComplianceRole {
+            authority: *authority,
+            config_account: config_pda(),
+            officer: *officer,
+            compliance_role: compliance_role_pda(officer),
+            system_program: system_program::ID,
+        },
+        instruction::GrantComplianceRole {},
+        vec![],
+    )
+}
+
+pub fn revoke_compliance_role(authority: &Pubkey, officer: &Pubkey) -> Instruction {
+    rwa_instruction(
+        accounts::RevokeComplianceRole {
+            authority: *authority,
+            config_account: config_pda(),
+            compliance_role: compliance_role_pda(officer),
+        },
+        instruction::RevokeComplianceRole {},
+        vec![],
+    )
+}
+
+pub fn add_to_denylist(
+    officer: &Pubkey,
+    wallet: &Pubkey,
+    args: instruction::AddToDenylist,
+) -> Instruction {
+    rwa_instruction(
+        accounts::AddToDenylist {
+            officer: *officer,
+            compliance_role: compliance_role_pda(officer),
+            wallet: *wallet,
+            denylist: denylist_pda(wallet),
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
+
+pub fn remove_from_denylist(officer: &Pubkey, wallet: &Pubkey) -> Instruction {
+    rwa_instruction(
+        accounts::RemoveFromDenylist {
+            officer: *officer,
+            compliance_role: compliance_role_pda(officer),
+            denylist: denylist_pda(wallet),
+        },
+        instruction::RemoveFromDenylist {},
+        vec![],
+    )
+}
+
+pub fn update_extra_account_meta_list(authority: &Pubkey, rwa_mint: &Pubkey) -> Instruction {
+    rwa_instruction(
+        accounts::UpdateExtraAccountMetaList {
+            authority: *authority,
+            config_account: config_pda(),
+            mint_authority: mint_authority_pda(rwa_mint),
+            rwa_mint: *rwa_mint,
+            extra_account_meta_list: extra_account_meta_list_pda(rwa_mint),
+            rwa_program: rwa_tokenization::ID,
+            transfer_hook_program: token_transfer_hook::ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateExtraAccountMetaList {},
+        vec![],
+    )
+}
+
+pub fn set_consumer_jurisdiction(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    consumer: &Pubkey,
+    args: instruction::SetConsumerJurisdiction,
+) -> Instruction {
+    let consumer_nft_mint = consumer_nft_pda(rwa_mint, consumer);
+    rwa_instruction(
+        accounts::SetConsumerJurisdiction {
+            authority: *authority,
+            config_account: config_pda(),
+            rwa_mint: *rwa_mint,
+            consumer: *consumer,
+            consumer_nft_mint,
+            consumer_controller: consumer_controller_pda(&consumer_nft_mint),
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+        vec![],
+    )
+}
@@ -0,0 +1,16 @@
+//! Rust client for the `rwa-tokenization` program and its `token-transfer-hook`:
+//! PDA derivation, instruction builders, account decoding and retirement certificate
+//! verification.
+
+pub mod accounts;
+pub mod certificate;
+pub mod instructions;
+pub mod pda;
+pub mod transfer_hook;
+
+pub use accounts::*;
+pub use certificate::*;
+pub use pda::*;
+
+pub use rwa_tokenization;
+pub use token_transfer_hook;
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token_2022};
+use rwa_tokenization::{
+    ACCOUNT_HOLD_SEED, CARBON_CREDIT_TOKEN_SEED, COMPLIANCE_ACTION_SEED, COMPLIANCE_ROLE_SEED,
+    CONSUMER_NFT_SEED, DENYLIST_SEED, GOVERNANCE_CONFIG_SEED, LOCKUP_SEED, MINTER_NFT_SEED,
+    MINT_AUTHORITY_SEED, RETIREMENT_CERT_SEED,
+};
+use token_transfer_hook::{
+    EVENT_AUTHORITY_SEED, EXTRA_ACCOUNT_METAS_SEED, TRANSFER_POLICY_SEED, TRANSFER_STATS_SEED,
+};
+
+fn rwa_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &rwa_tokenization::ID).0
+}
+
+fn hook_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &token_transfer_hook::ID).0
+}
+
+// rwa-tokenization accounts
+
+pub fn config_pda() -> Pubkey {
+    rwa_pda(&[GOVERNANCE_CONFIG_SEED])
+}
+
+pub fn rwa_mint_pda(symbol: &str) -> Pubkey {
+    rwa_pda(&[CARBON_CREDIT_TOKEN_SEED, symbol.as_bytes()])
+}
+
+pub fn mint_authority_pda(rwa_mint: &Pubkey) -> Pubkey {
+    rwa_pda(&[MINT_AUTHORITY_SEED, rwa_mint.as_ref()])
+}
+
+pub fn minter_nft_pda(rwa_mint: &Pubkey, minter: &Pubkey) -> Pubkey {
+    rwa_pda(&[MINTER_NFT_SEED, rwa_mint.as_ref(), minter.as_ref()])
+}
+
+pub fn minter_controller_pda(minter_nft_mint: &Pubkey) -> Pubkey {
+    rwa_pda(&[MINTER_NFT_SEED, minter_nft_mint.as_ref()])
+}
+
+pub fn consumer_nft_pda(rwa_mint: &Pubkey, consumer: &Pubkey) -> Pubkey {
+    rwa_pda(&[CONSUMER_NFT_SEED, rwa_mint.as_ref(), consumer.as_ref()])
+}
+
+pub fn consumer_controller_pda(consumer_nft_mint: &Pubkey) -> Pubkey {
+    rwa_pda(&[CONSUMER_NFT_SEED, consumer_nft_mint.as_ref()])
+}
+
+pub fn account_hold_pda(token_account: &Pubkey) -> Pubkey {
+    rwa_pda(&[ACCOUNT_HOLD_SEED, token_account.as_ref()])
+}
+
+pub fn compliance_action_pda(rwa_mint: &Pubkey, action_id: u64) -> Pubkey {
+    rwa_pda(&[
+        COMPLIANCE_ACTION_SEED,
+        rwa_mint.as_ref(),
+        action_id.to_le_bytes().as_ref(),
+    ])
+}
+
+pub fn compliance_role_pda(officer: &Pubkey) -> Pubkey {
+    rwa_pda(&[COMPLIANCE_ROLE_SEED, officer.as_ref()])
+}
+
+pub fn denylist_pda(wallet: &Pubkey) -> Pubkey {
+    rwa_pda(&[DENYLIST_SEED, wallet.as_ref()])
+}
+
+pub fn lockup_pda(rwa_mint: &Pubkey, holder: &Pubkey) -> Pubkey {
+    rwa_pda(&[LOCKUP_SEED, rwa_mint.as_ref(), holder.as_ref()])
+}
+
+pub fn retirement_certificate_pda(
+    rwa_mint: &Pubkey,
+    consumer: &Pubkey,
+    retirement_id: u64,
+) -> Pubkey {
+    rwa_pda(&[
+        RETIREMENT_CERT_SEED,
+        rwa_mint.as_ref(),
+        consumer.as_ref(),
+        retirement_id.to_le_bytes().as_ref(),
+    ])
+}
+
+// token-transfer-hook accounts
+
+pub fn extra_account_meta_list_pda(rwa_mint: &Pubkey) -> Pubkey {
+    hook_pda(&[EXTRA_ACCOUNT_METAS_SEED, rwa_mint.as_ref()])
+}
+
+pub fn transfer_policy_pda(rwa_mint: &Pubkey) -> Pubkey {
+    hook_pda(&[TRANSFER_POLICY_SEED, rwa_mint.as_ref()])
+}
+
+pub fn transfer_stats_pda(rwa_mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    hook_pda(&[TRANSFER_STATS_SEED, rwa_mint.as_ref(), owner.as_ref()])
+}
+
+// anchor event cpi authority of either program
+pub fn event_authority_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], program_id).0
+}
+
+// every token account of the protocol (rwa balances and certificates) is a Token-2022 ATA
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, &token_2022::ID)
+}
@@ -0,0 +1,98 @@
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::{instruction::Instruction, sysvar},
+    system_program, InstructionData, ToAccountMetas,
+};
+use anchor_spl::token_2022::{spl_token_2022, ID as TOKEN_2022_PROGRAM_ID};
+use token_transfer_hook::{MEMO_PROGRAM_ID, TRAVEL_RULE_MEMO_PREFIX};
+
+use crate::pda::{
+    consumer_controller_pda, consumer_nft_pda, denylist_pda, event_authority_pda,
+    extra_account_meta_list_pda, lockup_pda, transfer_policy_pda, transfer_stats_pda,
+};
+
+/// Accounts of a Token-2022 transfer of an RWA mint guarded by the transfer hook.
+#[derive(Clone, Copy, Debug)]
+pub struct HookTransferAccounts {
+    pub source: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    /// signer of the transfer: the source owner, a delegate or the permanent delegate
+    pub authority: Pubkey,
+    pub source_owner: Pubkey,
+    pub destination_owner: Pubkey,
+}
+
+/// The accounts Token-2022 needs to invoke the hook, in the order of the mint's
+/// `extra-account-metas` list (index 5 onwards), followed by the hook program and the list itself.
+///
+/// They are derived locally, so they must be kept in line with
+/// `InitializeExtraAccountMetaList::extra_account_metas`.
+pub fn extra_account_metas(accounts: &HookTransferAccounts) -> Vec<AccountMeta> {
+    let mint = &accounts.mint;
+    let source_cert = consumer_nft_pda(mint, &accounts.source_owner);
+    let destination_cert = consumer_nft_pda(mint, &accounts.destination_owner);
+    vec![
+        AccountMeta::new_readonly(rwa_tokenization::ID, false),
+        AccountMeta::new_readonly(source_cert, false),
+        AccountMeta::new_readonly(destination_cert, false),
+        AccountMeta::new_readonly(transfer_policy_pda(mint), false),
+        AccountMeta::new(transfer_stats_pda(mint, &accounts.source_owner), false),
+        AccountMeta::new_readonly(accounts.destination_owner, false),
+        AccountMeta::new_readonly(denylist_pda(&accounts.source_owner), false),
+        AccountMeta::new_readonly(denylist_pda(&accounts.destination_owner), false),
+        AccountMeta::new_readonly(consumer_controller_pda(&source_cert), false),
+        AccountMeta::new_readonly(consumer_controller_pda(&destination_cert), false),
+        AccountMeta::new_readonly(lockup_pda(mint, &accounts.source_owner), false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(event_authority_pda(&token_transfer_hook::ID), false),
+        AccountMeta::new_readonly(token_transfer_hook::ID, false),
+        AccountMeta::new_readonly(token_transfer_hook::ID, false),
+        AccountMeta::new_readonly(extra_account_meta_list_pda(mint), false),
+    ]
+}
+
+/// Token-2022 `transfer_checked` of an RWA mint carrying the transfer hook extra accounts.
+pub fn transfer_checked(accounts: &HookTransferAccounts, amount: u64, decimals: u8) -> Instruction {
+    let mut instruction = spl_token_2022::instruction::transfer_checked(
+        &TOKEN_2022_PROGRAM_ID,
+        &accounts.source,
+        &accounts.mint,
+        &accounts.destination,
+        &accounts.authority,
+        &[],
+        amount,
+        decimals,
+    )
+    .expect("token-2022 program id is valid");
+    instruction.accounts.extend(extra_account_metas(accounts));
+    instruction
+}
+
+/// Memo to place before a transfer above the policy travel-rule threshold.
+pub fn travel_rule_memo(data_hash: &[u8; 32]) -> Instruction {
+    let mut memo = TRAVEL_RULE_MEMO_PREFIX.to_vec();
+    for byte in data_hash {
+        memo.extend_from_slice(format!("{byte:02x}").as_bytes());
+    }
+    Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: memo,
+    }
+}
+
+pub fn initialize_transfer_stats(payer: &Pubkey, rwa_mint: &Pubkey, owner: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: token_transfer_hook::ID,
+        accounts: token_transfer_hook::accounts::InitializeTransferStats {
+            payer: *payer,
+            owner: *owner,
+            transfer_stats: transfer_stats_pda(rwa_mint, owner),
+            mint: *rwa_mint,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: token_transfer_hook::instruction::InitializeTransferStats {}.data(),
+    }
+}
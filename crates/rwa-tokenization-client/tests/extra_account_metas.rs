@@ -0,0 +1,80 @@
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::AccountMeta};
+use rwa_tokenization_client::{
+    extra_account_meta_list_pda, mint_authority_pda,
+    transfer_hook::{extra_account_metas, HookTransferAccounts},
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::{execute, ExecuteInstruction};
+use token_transfer_hook::InitializeExtraAccountMetaList;
+
+// token account data as far as the hook seeds read it: mint, then owner
+fn token_account_data(mint: &Pubkey, owner: &Pubkey) -> Vec<u8> {
+    let mut data = vec![0; 165];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data
+}
+
+async fn resolve_on_chain_list(accounts: &HookTransferAccounts) -> Vec<AccountMeta> {
+    let metas = InitializeExtraAccountMetaList::extra_account_metas().unwrap();
+    let mut list = vec![0; ExtraAccountMetaList::size_of(metas.len()).unwrap()];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut list, &metas).unwrap();
+
+    let mut instruction = execute(
+        &token_transfer_hook::ID,
+        &accounts.source,
+        &accounts.mint,
+        &accounts.destination,
+        &accounts.authority,
+        1,
+    );
+    instruction.accounts.push(AccountMeta::new_readonly(
+        extra_account_meta_list_pda(&accounts.mint),
+        false,
+    ));
+    let source_data = token_account_data(&accounts.mint, &accounts.source_owner);
+    let destination_data = token_account_data(&accounts.mint, &accounts.destination_owner);
+    ExtraAccountMetaList::add_to_instruction::<ExecuteInstruction, _, _>(
+        &mut instruction,
+        |address| {
+            let data = if address == accounts.source {
+                Some(source_data.clone())
+            } else if address == accounts.destination {
+                Some(destination_data.clone())
+            } else {
+                None
+            };
+            async move { Ok(data) }
+        },
+        &list,
+    )
+    .await
+    .unwrap();
+    instruction.accounts.split_off(5)
+}
+
+fn transfer(authority: Option<Pubkey>) -> HookTransferAccounts {
+    let mint = Pubkey::new_unique();
+    let source_owner = Pubkey::new_unique();
+    HookTransferAccounts {
+        source: Pubkey::new_unique(),
+        mint,
+        destination: Pubkey::new_unique(),
+        authority: authority.unwrap_or(source_owner),
+        source_owner,
+        destination_owner: Pubkey::new_unique(),
+    }
+}
+
+#[tokio::test]
+async fn extra_account_metas_match_the_hook_list() {
+    let mut clawback = transfer(None);
+    clawback.authority = mint_authority_pda(&clawback.mint);
+
+    for accounts in [transfer(None), clawback] {
+        let expected = resolve_on_chain_list(&accounts).await;
+        let derived = extra_account_metas(&accounts);
+        assert_eq!(derived[..expected.len()], expected[..]);
+        assert_eq!(derived.len(), expected.len() + 2);
+    }
+}
@@ -7,9 +7,19 @@ pub const MINTER_NFT_SEED: &[u8] = b"m"; // minter
 pub const CONSUMER_NFT_SEED: &[u8] = b"c"; // consumer
 pub const MINT_AUTHORITY_SEED: &[u8] = b"ma"; // mint authority
 pub const CARBON_CREDIT_TOKEN_SEED: &[u8] = b"cct"; // carbon credit token
+pub const ACCOUNT_HOLD_SEED: &[u8] = b"hold"; // compliance hold on a token account
+pub const COMPLIANCE_ACTION_SEED: &[u8] = b"ca"; // compliance action record
+pub const COMPLIANCE_ROLE_SEED: &[u8] = b"co"; // compliance officer
+pub const DENYLIST_SEED: &[u8] = b"deny"; // denylisted wallet, resolved by the transfer hook
+pub const LOCKUP_SEED: &[u8] = b"lockup"; // lockup of a (mint, holder), resolved by the transfer hook
+pub const RETIREMENT_CERT_SEED: &[u8] = b"rc"; // retirement certificate of a (mint, consumer, id)
+
+pub const MAX_COMPLIANCE_REASON_LEN: usize = 200;
 
 pub static AVAILABLE_CREDITS_KEY: &str = "available_credits";
 pub static MINTED_CREDITS_KEY: &str = "minted_credits";
 pub static RETIRED_CREDITS_KEY: &str = "retired_credits";
+pub static RETIRED_BY_KEY: &str = "retired_by";
+pub static RETIREMENT_ID_KEY: &str = "retirement_id";
 pub static RETIRED_CREDITS_CERT_NAME: &str = "Retired Carbon Credit";
 pub static RETIRED_CREDITS_CERT_SYMBOL: &str = "RCC";
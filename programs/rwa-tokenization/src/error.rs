@@ -10,5 +10,28 @@ pub enum MyErrorCode {
     NoCredits,
     InvalidAmount,
     InsufficientBalance,
+    #[msg("Certificate credits are not a number")]
     InvalidCredit,
+    #[msg("Invalid consumer certificate")]
+    InvalidConsumerCert,
+    #[msg("Token account is under compliance hold")]
+    AccountOnHold,
+    #[msg("Compliance reason is too long")]
+    ReasonTooLong,
+    #[msg("Clawback is not enabled for this mint")]
+    ClawbackDisabled,
+    #[msg("Token supply must be zero")]
+    SupplyNotZero,
+    #[msg("Transfer hook accounts are required for this mint")]
+    MissingTransferHookAccounts,
+    #[msg("Invalid jurisdiction")]
+    InvalidJurisdiction,
+    #[msg("Lockup must end in the future")]
+    InvalidLockup,
+    #[msg("Lockup account is required to lock minted tokens")]
+    MissingLockupAccount,
+    #[msg("Locked tokens of the holder unlock on a different schedule")]
+    LockupScheduleMismatch,
+    #[msg("Lockups need a transfer hook policy that enforces them")]
+    LockupNotEnforced,
 }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use token_transfer_hook::Jurisdiction;
+
+#[event]
+pub struct ConfigInitialized {
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct MinterCertIssued {
+    pub rwa_mint: Pubkey,
+    pub minter: Pubkey,
+    pub nft_mint: Pubkey,
+}
+
+#[event]
+pub struct QuotaUpdated {
+    pub rwa_mint: Pubkey,
+    pub minter: Pubkey,
+    pub nft_mint: Pubkey,
+    pub available_credits: u64,
+}
+
+#[event]
+pub struct ConsumerCertIssued {
+    pub rwa_mint: Pubkey,
+    pub consumer: Pubkey,
+    pub nft_mint: Pubkey,
+    pub issued_by: Pubkey,
+    pub jurisdiction: Jurisdiction,
+}
+
+#[event]
+pub struct RwaTokenCreated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub has_transfer_hook: bool,
+    pub has_fee: bool,
+    pub default_frozen: bool,
+    pub has_clawback: bool,
+}
+
+#[event]
+pub struct CreditsMinted {
+    pub rwa_mint: Pubkey,
+    pub minter: Pubkey,
+    pub receiver: Pubkey,
+    // whole credits taken from the minter quota, and the base units minted for them
+    pub credits: u64,
+    pub amount: u64,
+    pub lockup_until: Option<i64>,
+}
+
+#[event]
+pub struct CreditsRetired {
+    pub rwa_mint: Pubkey,
+    pub consumer: Pubkey,
+    pub amount: u64,
+    pub certificate_mint: Pubkey,
+}
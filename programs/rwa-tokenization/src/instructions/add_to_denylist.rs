@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use token_transfer_hook::DenyReason;
+
+use crate::{ComplianceRole, Denylist, COMPLIANCE_ROLE_SEED, DENYLIST_SEED};
+
+#[derive(Accounts)]
+pub struct AddToDenylist<'info> {
+    #[account(mut)]
+    pub officer: Signer<'info>,
+    #[account(
+        has_one = officer,
+        seeds = [COMPLIANCE_ROLE_SEED, officer.key().as_ref()],
+        bump = compliance_role.bump,
+    )]
+    pub compliance_role: Box<Account<'info, ComplianceRole>>,
+    /// CHECK: wallet to deny, any transfer from or to its token accounts is rejected by the hook
+    pub wallet: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = officer,
+        space = 8 + Denylist::INIT_SPACE,
+        seeds = [DENYLIST_SEED, wallet.key().as_ref()],
+        bump
+    )]
+    pub denylist: Box<Account<'info, Denylist>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddToDenylist<'info> {
+    pub fn handler(&mut self, reason: DenyReason, bumps: &AddToDenylistBumps) -> Result<()> {
+        self.denylist.set_inner(Denylist {
+            reason,
+            wallet: self.wallet.key(),
+            officer: self.officer.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            bump: bumps.denylist,
+        });
+        Ok(())
+    }
+}
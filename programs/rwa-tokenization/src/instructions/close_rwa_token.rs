@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{close_account, CloseAccount, Token2022},
+    token_interface::Mint,
+};
+use token_transfer_hook::{cpi::accounts::CloseExtraAccountMetaList, program::TokenTransferHook};
+
+use crate::{
+    error::MyErrorCode, GovernanceConfig, MintAuthority, GOVERNANCE_CONFIG_SEED,
+    MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CloseRwaToken<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        mut,
+        close = authority,
+        constraint = mint_authority.mint == rwa_mint.key(),
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = rwa_mint.supply == 0 @ MyErrorCode::SupplyNotZero,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: ExtraAccountMetaList Account of the transfer hook, closed via CPI
+    #[account(mut)]
+    pub extra_account_meta_list: Option<UncheckedAccount<'info>>,
+    #[account(
+        constraint = Some(transfer_hook_program.key()) == mint_authority.transfer_hook,
+    )]
+    pub transfer_hook_program: Option<Program<'info, TokenTransferHook>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CloseRwaToken<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        if self.mint_authority.transfer_hook.is_some() {
+            let (Some(transfer_hook_program), Some(extra_account_meta_list)) =
+                (&self.transfer_hook_program, &self.extra_account_meta_list)
+            else {
+                return Err(MyErrorCode::MissingTransferHookAccounts.into());
+            };
+
+            // the hook only accepts the mint's transfer hook authority, our mint authority PDA
+            token_transfer_hook::cpi::close_extra_account_meta_list(CpiContext::new_with_signer(
+                transfer_hook_program.to_account_info(),
+                CloseExtraAccountMetaList {
+                    authority: self.mint_authority.to_account_info(),
+                    destination: self.authority.to_account_info(),
+                    extra_account_meta_list: extra_account_meta_list.to_account_info(),
+                    mint: self.rwa_mint.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.rwa_mint.to_account_info(),
+                destination: self.authority.to_account_info(),
+                authority: self.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    clawback_transfer, error::MyErrorCode, is_permanent_delegate, ComplianceAction,
+    ComplianceActionKind, GovernanceConfig, MintAuthority, COMPLIANCE_ACTION_SEED,
+    GOVERNANCE_CONFIG_SEED, MAX_COMPLIANCE_REASON_LEN, MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ForceTransfer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = rwa_mint,
+    )]
+    pub source_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = rwa_mint,
+        constraint = destination_token_account.key() != source_token_account.key(),
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ComplianceAction::INIT_SPACE,
+        seeds = [COMPLIANCE_ACTION_SEED, rwa_mint.key().as_ref(), action_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub compliance_action: Box<Account<'info, ComplianceAction>>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForceTransfer<'info> {
+    pub fn handler(
+        &mut self,
+        action_id: u64,
+        amount: u64,
+        reason: String,
+        document_hash: [u8; 32],
+        remaining_accounts: &[AccountInfo<'info>],
+        bumps: &ForceTransferBumps,
+    ) -> Result<()> {
+        require!(amount > 0, MyErrorCode::InvalidAmount);
+        require!(
+            reason.len() <= MAX_COMPLIANCE_REASON_LEN,
+            MyErrorCode::ReasonTooLong
+        );
+        require!(
+            self.source_token_account.amount >= amount,
+            MyErrorCode::InsufficientBalance
+        );
+        require!(
+            is_permanent_delegate(&self.rwa_mint.to_account_info(), &self.mint_authority.key())?,
+            MyErrorCode::ClawbackDisabled
+        );
+
+        self.move_balance(amount, remaining_accounts)?;
+
+        self.compliance_action.set_inner(ComplianceAction {
+            mint: self.rwa_mint.key(),
+            action_id,
+            kind: ComplianceActionKind::ForceTransfer,
+            authority: self.authority.key(),
+            source: self.source_token_account.key(),
+            destination: Some(self.destination_token_account.key()),
+            amount,
+            reason,
+            document_hash,
+            created_at: Clock::get()?.unix_timestamp,
+            bump: bumps.compliance_action,
+        });
+        Ok(())
+    }
+
+    fn move_balance(&self, amount: u64, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        clawback_transfer(
+            self.token_program.to_account_info(),
+            &self.rwa_mint,
+            &self.source_token_account,
+            &self.destination_token_account,
+            self.mint_authority.to_account_info(),
+            remaining_accounts,
+            amount,
+            signer_seeds,
+        )
+    }
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    AccountHold, GovernanceConfig, MintAuthority, ACCOUNT_HOLD_SEED, GOVERNANCE_CONFIG_SEED,
+    MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct FreezeAccount<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mint::token_program = token_program,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = rwa_mint,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AccountHold::INIT_SPACE,
+        seeds = [ACCOUNT_HOLD_SEED, token_account.key().as_ref()],
+        bump
+    )]
+    pub account_hold: Box<Account<'info, AccountHold>>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FreezeAccount<'info> {
+    pub fn handler(&mut self, bumps: &FreezeAccountBumps) -> Result<()> {
+        self.account_hold.set_inner(AccountHold {
+            mint: self.rwa_mint.key(),
+            token_account: self.token_account.key(),
+            authority: self.authority.key(),
+            froze_account: !self.token_account.is_frozen(),
+            bump: bumps.account_hold,
+        });
+
+        // the account may already be frozen by the mint's default account state
+        if !self.account_hold.froze_account {
+            return Ok(());
+        }
+
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_2022::freeze_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token_2022::FreezeAccount {
+                account: self.token_account.to_account_info(),
+                mint: self.rwa_mint.to_account_info(),
+                authority: self.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        Ok(())
+    }
+}
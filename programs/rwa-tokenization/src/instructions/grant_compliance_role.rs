@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{ComplianceRole, GovernanceConfig, COMPLIANCE_ROLE_SEED, GOVERNANCE_CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct GrantComplianceRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    /// CHECK: wallet receiving the compliance role
+    pub officer: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ComplianceRole::INIT_SPACE,
+        seeds = [COMPLIANCE_ROLE_SEED, officer.key().as_ref()],
+        bump
+    )]
+    pub compliance_role: Box<Account<'info, ComplianceRole>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantComplianceRole<'info> {
+    pub fn handler(&mut self, bumps: &GrantComplianceRoleBumps) -> Result<()> {
+        self.compliance_role.set_inner(ComplianceRole {
+            officer: self.officer.key(),
+            granted_by: self.authority.key(),
+            bump: bumps.compliance_role,
+        });
+        Ok(())
+    }
+}
@@ -1,20 +1,28 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022::{initialize_mint2, InitializeMint2, Token2022},
+    token_2022::{
+        initialize_mint2, spl_token_2022::state::AccountState, InitializeMint2, Token2022,
+    },
     token_interface::{
-        metadata_pointer_initialize, mint_close_authority_initialize, token_metadata_initialize,
-        transfer_fee_initialize, transfer_hook_initialize, MetadataPointerInitialize,
-        MintCloseAuthorityInitialize, TokenMetadataInitialize, TransferFeeInitialize,
-        TransferHookInitialize,
+        default_account_state_initialize, metadata_pointer_initialize,
+        mint_close_authority_initialize, permanent_delegate_initialize, token_metadata_initialize,
+        transfer_fee_initialize, transfer_hook_initialize, DefaultAccountStateInitialize,
+        MetadataPointerInitialize, MintCloseAuthorityInitialize, PermanentDelegateInitialize,
+        TokenMetadataInitialize, TransferFeeInitialize, TransferHookInitialize,
     },
 };
+use token_transfer_hook::{
+    cpi::accounts::InitializeExtraAccountMetaList, program::TokenTransferHook,
+};
 
 use crate::{
-    get_mint_space_with_extensions, update_account_lamports_to_minimum_balance, GovernanceConfig,
-    MintAuthority, CARBON_CREDIT_TOKEN_SEED, GOVERNANCE_CONFIG_SEED, MINT_AUTHORITY_SEED,
+    error::MyErrorCode, get_mint_space_with_extensions, program::RwaTokenization,
+    update_account_lamports_to_minimum_balance, GovernanceConfig, MintAuthority, RwaTokenCreated,
+    CARBON_CREDIT_TOKEN_SEED, GOVERNANCE_CONFIG_SEED, MINT_AUTHORITY_SEED,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(
     name: String,
@@ -23,6 +31,10 @@ use crate::{
     uri: String,
     is_close: bool,
     has_fee: bool,
+    transfer_fee_basis_points: Option<u16>,
+    maximum_fee: Option<u64>,
+    default_frozen: bool,
+    has_clawback: bool,
 )]
 pub struct InitRwaToken<'info> {
     #[account(mut)]
@@ -45,21 +57,24 @@ pub struct InitRwaToken<'info> {
     #[account(
         init,
         payer = authority,
-        space = get_mint_space_with_extensions(is_close, has_fee)?,
+        space = get_mint_space_with_extensions(is_close, has_fee, default_frozen, has_clawback)?,
         seeds = [CARBON_CREDIT_TOKEN_SEED, symbol.as_ref()],
         bump,
         owner = token_program.key()
     )]
     pub mint: UncheckedAccount<'info>,
-    /// CHECK: This is transfer hook program
-    #[account(executable)]
-    pub transfer_hook_program: AccountInfo<'info>,
+    /// CHECK: ExtraAccountMetaList Account of the transfer hook, created via CPI when is_close
+    #[account(mut)]
+    pub extra_account_meta_list: Option<UncheckedAccount<'info>>,
+    pub transfer_hook_program: Program<'info, TokenTransferHook>,
+    pub rwa_program: Program<'info, RwaTokenization>,
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> InitRwaToken<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn handler(
         &mut self,
         name: String,
@@ -70,8 +85,22 @@ impl<'info> InitRwaToken<'info> {
         has_fee: bool,
         transfer_fee_basis_points: Option<u16>,
         maximum_fee: Option<u64>,
+        default_frozen: bool,
+        has_clawback: bool,
         bump: &InitRwaTokenBumps,
-    ) -> Result<()> {
+    ) -> Result<RwaTokenCreated> {
+        let event = RwaTokenCreated {
+            mint: self.mint.key(),
+            authority: self.authority.key(),
+            name: name.clone(),
+            symbol: symbol.clone(),
+            decimals,
+            has_transfer_hook: is_close,
+            has_fee,
+            default_frozen,
+            has_clawback,
+        };
+
         self.mint_authority.set_inner(MintAuthority {
             authority: self.authority.key(),
             mint: self.mint.key(),
@@ -82,7 +111,14 @@ impl<'info> InitRwaToken<'info> {
             },
             bump: bump.mint_authority,
         });
-        self.init_extensions_and_mint(is_close, has_fee, transfer_fee_basis_points, maximum_fee)?;
+        self.init_extensions_and_mint(
+            is_close,
+            has_fee,
+            transfer_fee_basis_points,
+            maximum_fee,
+            default_frozen,
+            has_clawback,
+        )?;
 
         initialize_mint2(
             CpiContext::new(
@@ -103,6 +139,40 @@ impl<'info> InitRwaToken<'info> {
             self.authority.to_account_info(),
             self.system_program.to_account_info(),
         )?;
+
+        if is_close {
+            self.init_extra_account_meta_list()?;
+        }
+        Ok(event)
+    }
+
+    fn init_extra_account_meta_list(&mut self) -> Result<()> {
+        let extra_account_meta_list = self
+            .extra_account_meta_list
+            .as_ref()
+            .ok_or(MyErrorCode::MissingTransferHookAccounts)?;
+
+        let mint_key = self.mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // the hook only accepts initialization signed by our mint authority PDA
+        token_transfer_hook::cpi::initialize_extra_account_meta_list(CpiContext::new_with_signer(
+            self.transfer_hook_program.to_account_info(),
+            InitializeExtraAccountMetaList {
+                payer: self.authority.to_account_info(),
+                authority: self.mint_authority.to_account_info(),
+                extra_account_meta_list: extra_account_meta_list.to_account_info(),
+                mint: self.mint.to_account_info(),
+                rwa_program: self.rwa_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
         Ok(())
     }
 
@@ -112,6 +182,8 @@ impl<'info> InitRwaToken<'info> {
         has_fee: bool,
         transfer_fee_basis_points: Option<u16>,
         maximum_fee: Option<u64>,
+        default_frozen: bool,
+        has_clawback: bool,
     ) -> Result<()> {
         // Some extensions init must come before the instruction to initialize the mint data
 
@@ -173,6 +245,34 @@ impl<'info> InitRwaToken<'info> {
             )?;
         }
 
+        if default_frozen {
+            // every new token account starts frozen until the owner is thawed by KYC
+            default_account_state_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    DefaultAccountStateInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.mint.to_account_info(),
+                    },
+                ),
+                &AccountState::Frozen,
+            )?;
+        }
+
+        if has_clawback {
+            // mint authority can move or burn any holder balance for regulatory clawback
+            permanent_delegate_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    PermanentDelegateInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.mint.to_account_info(),
+                    },
+                ),
+                &self.mint_authority.key(),
+            )?;
+        }
+
         Ok(())
     }
 
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::{GovernanceConfig, GOVERNANCE_CONFIG_SEED};
+use crate::{ConfigInitialized, GovernanceConfig, GOVERNANCE_CONFIG_SEED};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeConfig<'info> {
     #[account(mut)]
@@ -18,12 +19,14 @@ pub struct InitializeConfig<'info> {
 }
 
 impl<'info> InitializeConfig<'info> {
-    pub fn handler(&mut self, bumps: &InitializeConfigBumps) -> Result<()> {
+    pub fn handler(&mut self, bumps: &InitializeConfigBumps) -> Result<ConfigInitialized> {
         self.config_account.set_inner(GovernanceConfig {
             authority: self.singer.key(),
             is_initialized: true,
             bump: bumps.config_account,
         });
-        Ok(())
+        Ok(ConfigInitialized {
+            authority: self.singer.key(),
+        })
     }
 }
@@ -11,10 +11,14 @@ use anchor_spl::{
     },
 };
 
+use token_transfer_hook::Jurisdiction;
+
 use crate::{
-    update_account_minimum_lamports, ConsumerController, CONSUMER_NFT_SEED, MINTER_NFT_SEED,
+    error::MyErrorCode, update_account_minimum_lamports, ConsumerCertIssued, ConsumerController,
+    CONSUMER_NFT_SEED, MINTER_NFT_SEED,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct IssueConsumerCert<'info> {
     #[account(mut)]
@@ -76,18 +80,28 @@ impl<'info> IssueConsumerCert<'info> {
         name: String,
         symbol: String,
         uri: String,
+        jurisdiction: Jurisdiction,
         bumps: &IssueConsumerCertBumps,
-    ) -> Result<()> {
+    ) -> Result<ConsumerCertIssued> {
+        require!(jurisdiction.is_valid(), MyErrorCode::InvalidJurisdiction);
+
         self.consumer_controller.set_inner(ConsumerController {
             rwa_mint: self.rwa_mint.key(),
             mint: self.mint.key(),
             user: self.receiver.key(),
             bump: bumps.consumer_controller,
+            jurisdiction,
         });
         self.update_account_lamports_by_metadata(name.clone(), symbol.clone(), uri.clone())?;
         self.init_nft_metadata(name, symbol, uri)?;
         self.mint_and_send_nft()?;
-        Ok(())
+        Ok(ConsumerCertIssued {
+            rwa_mint: self.rwa_mint.key(),
+            consumer: self.receiver.key(),
+            nft_mint: self.mint.key(),
+            issued_by: self.minter.key(),
+            jurisdiction,
+        })
     }
 
     fn mint_and_send_nft(&mut self) -> Result<()> {
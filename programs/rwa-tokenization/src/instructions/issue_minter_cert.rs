@@ -15,9 +15,11 @@ use anchor_spl::{
 
 use crate::{
     minter_controller::MinterController, update_account_minimum_lamports, GovernanceConfig,
-    AVAILABLE_CREDITS_KEY, GOVERNANCE_CONFIG_SEED, MINTED_CREDITS_KEY, MINTER_NFT_SEED,
+    MinterCertIssued, AVAILABLE_CREDITS_KEY, GOVERNANCE_CONFIG_SEED, MINTED_CREDITS_KEY,
+    MINTER_NFT_SEED,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct IssueMinterCert<'info> {
     #[account(mut)]
@@ -76,7 +78,7 @@ impl<'info> IssueMinterCert<'info> {
         symbol: String,
         uri: String,
         bumps: &IssueMinterCertBumps,
-    ) -> Result<()> {
+    ) -> Result<MinterCertIssued> {
         self.minter_controller.set_inner(MinterController {
             rwa_mint: self.permissioned_mint.key(),
             mint: self.mint.key(),
@@ -86,7 +88,11 @@ impl<'info> IssueMinterCert<'info> {
         self.update_account_lamports_by_metadata(name.clone(), symbol.clone(), uri.clone())?;
         self.init_nft_metadata(name, symbol, uri)?;
         self.mint_and_send_nft()?;
-        Ok(())
+        Ok(MinterCertIssued {
+            rwa_mint: self.permissioned_mint.key(),
+            minter: self.receiver.key(),
+            nft_mint: self.mint.key(),
+        })
     }
 
     fn mint_and_send_nft(&mut self) -> Result<()> {
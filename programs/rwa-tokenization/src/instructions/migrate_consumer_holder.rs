@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    ConsumerController, GovernanceConfig, HolderMigration, Lockup, MintAuthority,
+    CONSUMER_NFT_SEED, GOVERNANCE_CONFIG_SEED, LOCKUP_SEED, MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct MigrateConsumerHolder<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    /// CHECK: Lost wallet holding the current consumer certificate
+    pub old_wallet: AccountInfo<'info>,
+    /// CHECK: Recovery wallet receiving the new consumer certificate
+    #[account(constraint = new_wallet.key() != old_wallet.key())]
+    pub new_wallet: AccountInfo<'info>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        close = authority,
+        constraint = old_consumer_controller.rwa_mint == rwa_mint.key(),
+        constraint = old_consumer_controller.user == old_wallet.key(),
+        seeds = [CONSUMER_NFT_SEED, old_nft_mint.key().as_ref()],
+        bump = old_consumer_controller.bump
+    )]
+    pub old_consumer_controller: Box<Account<'info, ConsumerController>>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        mint::decimals = 0,
+        seeds = [CONSUMER_NFT_SEED, rwa_mint.key().as_ref(), old_wallet.key.as_ref()],
+        bump
+    )]
+    pub old_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = old_nft_mint,
+        associated_token::authority = old_wallet
+    )]
+    pub old_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ConsumerController::INIT_SPACE,
+        seeds = [CONSUMER_NFT_SEED, new_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub new_consumer_controller: Box<Account<'info, ConsumerController>>,
+    #[account(
+      init,
+      payer = authority,
+      mint::token_program = token_program,
+      mint::decimals = 0,
+      mint::authority = new_consumer_controller,
+      extensions::metadata_pointer::authority = new_consumer_controller,
+      extensions::metadata_pointer::metadata_address = new_nft_mint,
+      extensions::close_authority::authority = new_consumer_controller,
+      extensions::permanent_delegate::delegate = new_consumer_controller,
+      seeds = [CONSUMER_NFT_SEED, rwa_mint.key().as_ref(), new_wallet.key.as_ref()],
+      bump
+    )]
+    pub new_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+      init,
+      payer = authority,
+      associated_token::token_program = token_program,
+      associated_token::mint = new_nft_mint,
+      associated_token::authority = new_wallet
+    )]
+    pub new_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = rwa_mint,
+        token::authority = old_wallet,
+    )]
+    pub old_rwa_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = rwa_mint,
+        token::authority = new_wallet,
+    )]
+    pub new_rwa_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        seeds = [LOCKUP_SEED, rwa_mint.key().as_ref(), old_wallet.key.as_ref()],
+        bump = old_lockup.bump,
+    )]
+    pub old_lockup: Option<Box<Account<'info, Lockup>>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Lockup::INIT_SPACE,
+        seeds = [LOCKUP_SEED, rwa_mint.key().as_ref(), new_wallet.key.as_ref()],
+        bump
+    )]
+    pub new_lockup: Option<Box<Account<'info, Lockup>>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> MigrateConsumerHolder<'info> {
+    pub fn handler(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        bumps: &MigrateConsumerHolderBumps,
+    ) -> Result<()> {
+        self.new_consumer_controller.set_inner(ConsumerController {
+            rwa_mint: self.rwa_mint.key(),
+            mint: self.new_nft_mint.key(),
+            user: self.new_wallet.key(),
+            bump: bumps.new_consumer_controller,
+            jurisdiction: self.old_consumer_controller.jurisdiction,
+        });
+
+        HolderMigration {
+            authority: self.authority.to_account_info(),
+            rwa_mint: &self.rwa_mint,
+            mint_authority: self.mint_authority.to_account_info(),
+            mint_authority_bump: self.mint_authority.bump,
+            nft_seed: CONSUMER_NFT_SEED,
+            old_controller: self.old_consumer_controller.to_account_info(),
+            old_controller_bump: self.old_consumer_controller.bump,
+            old_nft_mint: &self.old_nft_mint,
+            old_nft_token_account: &self.old_nft_token_account,
+            new_controller: self.new_consumer_controller.to_account_info(),
+            new_controller_bump: bumps.new_consumer_controller,
+            new_nft_mint: &self.new_nft_mint,
+            new_nft_token_account: &self.new_nft_token_account,
+            old_rwa_token_account: self.old_rwa_token_account.as_deref(),
+            new_rwa_token_account: self.new_rwa_token_account.as_deref(),
+            old_lockup: self.old_lockup.as_deref_mut(),
+            new_lockup: self.new_lockup.as_deref_mut(),
+            new_lockup_bump: bumps.new_lockup,
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+        .migrate(remaining_accounts)
+    }
+}
@@ -14,12 +14,15 @@ use anchor_spl::{
         token_metadata_update_field, Mint, MintTo, TokenAccount, TokenMetadataUpdateField,
     },
 };
+use token_transfer_hook::{TransferPolicy, TRANSFER_POLICY_SEED};
 
 use crate::{
-    error::MyErrorCode, MintAuthority, MinterController, AVAILABLE_CREDITS_KEY, MINTED_CREDITS_KEY,
+    error::MyErrorCode, freeze_token_account, thaw_token_account, CreditsMinted, Lockup,
+    MintAuthority, MinterController, AVAILABLE_CREDITS_KEY, LOCKUP_SEED, MINTED_CREDITS_KEY,
     MINTER_NFT_SEED, MINT_AUTHORITY_SEED,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MintRwaToken<'info> {
     #[account(mut)]
@@ -72,6 +75,21 @@ pub struct MintRwaToken<'info> {
         associated_token::authority = minter
     )]
     pub minter_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Lockup::INIT_SPACE,
+        seeds = [LOCKUP_SEED, rwa_mint.key().as_ref(), receiver.key.as_ref()],
+        bump
+    )]
+    pub lockup: Option<Box<Account<'info, Lockup>>>,
+    /// CHECK: TransferPolicy of the mint, read by hand since the hook may not have created it
+    #[account(
+        seeds = [TRANSFER_POLICY_SEED, rwa_mint.key().as_ref()],
+        seeds::program = token_transfer_hook::ID,
+        bump
+    )]
+    pub transfer_policy: Option<UncheckedAccount<'info>>,
     // /// CHECK: This is transfer hook program
     // pub transfer_hook_program: AccountInfo<'info>,
     pub token_program: Program<'info, Token2022>,
@@ -80,7 +98,13 @@ pub struct MintRwaToken<'info> {
 }
 
 impl<'info> MintRwaToken<'info> {
-    pub fn handler(&mut self, amount: u64) -> Result<()> {
+    pub fn handler(
+        &mut self,
+        amount: u64,
+        lockup_until: Option<i64>,
+        linear_vesting: bool,
+        bumps: &MintRwaTokenBumps,
+    ) -> Result<CreditsMinted> {
         require!(amount > 0, MyErrorCode::InvalidAmount);
         // read metadata from nft mint
 
@@ -91,12 +115,14 @@ impl<'info> MintRwaToken<'info> {
 
         if let Some((_, value)) = available_credits {
             let available_credits = value.parse::<u64>().map_err(|_| MyErrorCode::NoCredits)?;
-            require!(
-                available_credits >= amount,
-                MyErrorCode::InsufficientCredits
-            );
+            let remaining_credits = available_credits
+                .checked_sub(amount)
+                .ok_or(MyErrorCode::InsufficientCredits)?;
 
-            self.mint_to_recevier(amount)?;
+            let mint_amount = self.mint_to_recevier(amount)?;
+            if let Some(lockup_until) = lockup_until {
+                self.lock_minted(mint_amount, lockup_until, linear_vesting, bumps)?;
+            }
 
             let minter_nft_mint_key = self.minter_nft_mint.key();
             let seeds = &[
@@ -117,12 +143,8 @@ impl<'info> MintRwaToken<'info> {
                     signer_seeds,
                 ),
                 Field::Key(AVAILABLE_CREDITS_KEY.to_string()),
-                available_credits.checked_sub(amount).unwrap().to_string(),
+                remaining_credits.to_string(),
             )?;
-            available_credits
-                .checked_sub(amount)
-                .ok_or(MyErrorCode::Overflow)?
-                .to_string();
 
             let minted_credits = additional_metadata
                 .iter()
@@ -130,7 +152,9 @@ impl<'info> MintRwaToken<'info> {
 
             let mut updated_minted_credits = amount;
             if let Some((_, value)) = minted_credits {
-                let minted_credits = value.parse::<u64>().unwrap();
+                let minted_credits = value
+                    .parse::<u64>()
+                    .map_err(|_| MyErrorCode::InvalidCredit)?;
                 updated_minted_credits = minted_credits
                     .checked_add(amount)
                     .ok_or(MyErrorCode::Overflow)?;
@@ -149,10 +173,18 @@ impl<'info> MintRwaToken<'info> {
                 Field::Key(MINTED_CREDITS_KEY.to_string()),
                 updated_minted_credits.to_string(),
             )?;
+
+            Ok(CreditsMinted {
+                rwa_mint: self.rwa_mint.key(),
+                minter: self.minter.key(),
+                receiver: self.receiver.key(),
+                credits: amount,
+                amount: mint_amount,
+                lockup_until,
+            })
         } else {
-            return Err(MyErrorCode::InsufficientCredits.into());
+            Err(MyErrorCode::InsufficientCredits.into())
         }
-        Ok(())
     }
 
     fn get_additional_metadata(&self) -> Result<Vec<(String, String)>> {
@@ -164,7 +196,44 @@ impl<'info> MintRwaToken<'info> {
         Ok(metadata.additional_metadata)
     }
 
-    fn mint_to_recevier(&mut self, amount: u64) -> Result<()> {
+    fn lock_minted(
+        &mut self,
+        mint_amount: u64,
+        lockup_until: i64,
+        linear_vesting: bool,
+        bumps: &MintRwaTokenBumps,
+    ) -> Result<()> {
+        require!(self.lockups_enforced()?, MyErrorCode::LockupNotEnforced);
+        let now = Clock::get()?.unix_timestamp;
+        require!(lockup_until > now, MyErrorCode::InvalidLockup);
+        let lockup = self
+            .lockup
+            .as_mut()
+            .ok_or(MyErrorCode::MissingLockupAccount)?;
+        let bump = bumps.lockup.ok_or(MyErrorCode::MissingLockupAccount)?;
+
+        lockup.lock(mint_amount, now, lockup_until, linear_vesting)?;
+        lockup.mint = self.rwa_mint.key();
+        lockup.holder = self.receiver.key();
+        lockup.bump = bump;
+        Ok(())
+    }
+
+    // only the transfer hook keeps locked tokens in place
+    fn lockups_enforced(&self) -> Result<bool> {
+        let Some(policy) = &self.transfer_policy else {
+            return Ok(false);
+        };
+        if self.mint_authority.transfer_hook != Some(token_transfer_hook::ID)
+            || policy.owner != &token_transfer_hook::ID
+        {
+            return Ok(false);
+        }
+        let data = policy.try_borrow_data()?;
+        Ok(TransferPolicy::try_deserialize(&mut &data[..])?.enforce_lockups)
+    }
+
+    fn mint_to_recevier(&mut self, amount: u64) -> Result<u64> {
         let mint_key = self.rwa_mint.key();
         let seeds = &[
             MINT_AUTHORITY_SEED,
@@ -173,9 +242,22 @@ impl<'info> MintRwaToken<'info> {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        let mint_amount = amount
-            .checked_mul(10u64.pow(self.rwa_mint.decimals as u32))
-            .unwrap();
+        let mint_amount = 10u64
+            .checked_pow(self.rwa_mint.decimals as u32)
+            .and_then(|scale| amount.checked_mul(scale))
+            .ok_or(MyErrorCode::Overflow)?;
+
+        // default frozen mints: issue into the account but keep it frozen until KYC thaw
+        let is_frozen = self.receiver_token_account.is_frozen();
+        if is_frozen {
+            thaw_token_account(
+                self.token_program.to_account_info(),
+                self.receiver_token_account.to_account_info(),
+                self.rwa_mint.to_account_info(),
+                self.mint_authority.to_account_info(),
+                signer_seeds,
+            )?;
+        }
 
         mint_to(
             CpiContext::new_with_signer(
@@ -189,6 +271,16 @@ impl<'info> MintRwaToken<'info> {
             ),
             mint_amount,
         )?;
-        Ok(())
+
+        if is_frozen {
+            freeze_token_account(
+                self.token_program.to_account_info(),
+                self.receiver_token_account.to_account_info(),
+                self.rwa_mint.to_account_info(),
+                self.mint_authority.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+        Ok(mint_amount)
     }
 }
@@ -25,3 +25,79 @@ pub use mint_rwa_token::*;
 pub mod retire_token;
 
 pub use retire_token::*;
+
+pub mod thaw_for_consumer;
+
+pub use thaw_for_consumer::*;
+
+pub mod freeze_account;
+
+pub use freeze_account::*;
+
+pub mod release_hold;
+
+pub use release_hold::*;
+
+pub mod force_transfer;
+
+pub use force_transfer::*;
+
+pub mod force_burn;
+
+pub use force_burn::*;
+
+pub mod migrate_minter_holder;
+
+pub use migrate_minter_holder::*;
+
+pub mod migrate_consumer_holder;
+
+pub use migrate_consumer_holder::*;
+
+pub mod close_rwa_token;
+
+pub use close_rwa_token::*;
+
+pub mod update_rwa_metadata;
+
+pub use update_rwa_metadata::*;
+
+pub mod set_transfer_policy;
+
+pub use set_transfer_policy::*;
+
+pub mod set_allowed_account;
+
+pub use set_allowed_account::*;
+
+pub mod set_allowed_program_owner;
+
+pub use set_allowed_program_owner::*;
+
+pub mod grant_compliance_role;
+
+pub use grant_compliance_role::*;
+
+pub mod revoke_compliance_role;
+
+pub use revoke_compliance_role::*;
+
+pub mod add_to_denylist;
+
+pub use add_to_denylist::*;
+
+pub mod remove_from_denylist;
+
+pub use remove_from_denylist::*;
+
+pub mod set_jurisdiction_rules;
+
+pub use set_jurisdiction_rules::*;
+
+pub mod update_extra_account_meta_list;
+
+pub use update_extra_account_meta_list::*;
+
+pub mod set_consumer_jurisdiction;
+
+pub use set_consumer_jurisdiction::*;
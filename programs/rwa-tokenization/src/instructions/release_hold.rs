@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{thaw_account, ThawAccount, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    AccountHold, GovernanceConfig, MintAuthority, ACCOUNT_HOLD_SEED, GOVERNANCE_CONFIG_SEED,
+    MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ReleaseHold<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mint::token_program = token_program,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = rwa_mint,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = token_account,
+        seeds = [ACCOUNT_HOLD_SEED, token_account.key().as_ref()],
+        bump = account_hold.bump,
+    )]
+    pub account_hold: Box<Account<'info, AccountHold>>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> ReleaseHold<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        // an account frozen before the hold (not yet thawed after KYC) stays frozen
+        if !self.account_hold.froze_account || !self.token_account.is_frozen() {
+            return Ok(());
+        }
+
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        thaw_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            ThawAccount {
+                account: self.token_account.to_account_info(),
+                mint: self.rwa_mint.to_account_info(),
+                authority: self.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{ComplianceRole, Denylist, COMPLIANCE_ROLE_SEED, DENYLIST_SEED};
+
+#[derive(Accounts)]
+pub struct RemoveFromDenylist<'info> {
+    #[account(mut)]
+    pub officer: Signer<'info>,
+    #[account(
+        has_one = officer,
+        seeds = [COMPLIANCE_ROLE_SEED, officer.key().as_ref()],
+        bump = compliance_role.bump,
+    )]
+    pub compliance_role: Box<Account<'info, ComplianceRole>>,
+    #[account(
+        mut,
+        close = officer,
+        seeds = [DENYLIST_SEED, denylist.wallet.as_ref()],
+        bump = denylist.bump,
+    )]
+    pub denylist: Box<Account<'info, Denylist>>,
+}
+
+impl<'info> RemoveFromDenylist<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
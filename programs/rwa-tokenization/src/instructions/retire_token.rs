@@ -16,12 +16,14 @@ use anchor_spl::{
 };
 
 use crate::{
-    error::MyErrorCode, update_account_minimum_lamports, MintAuthority, MINT_AUTHORITY_SEED,
-    RETIRED_CREDITS_CERT_NAME, RETIRED_CREDITS_CERT_SYMBOL, RETIRED_CREDITS_KEY,
+    error::MyErrorCode, update_account_minimum_lamports, CreditsRetired, MintAuthority,
+    MINT_AUTHORITY_SEED, RETIRED_BY_KEY, RETIRED_CREDITS_CERT_NAME, RETIRED_CREDITS_CERT_SYMBOL,
+    RETIRED_CREDITS_KEY, RETIREMENT_CERT_SEED, RETIREMENT_ID_KEY,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(amount: u64)]
+#[instruction(amount: u64, retirement_id: u64)]
 pub struct RetireToken<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -52,9 +54,16 @@ pub struct RetireToken<'info> {
       mint::decimals = 0,
       mint::authority = mint_authority,
       extensions::metadata_pointer::authority = mint_authority,
-      extensions::metadata_pointer::metadata_address = mint,
+      extensions::metadata_pointer::metadata_address = nft_mint,
       extensions::close_authority::authority = mint_authority,
       extensions::permanent_delegate::delegate = mint_authority,
+      seeds = [
+          RETIREMENT_CERT_SEED,
+          mint.key().as_ref(),
+          consumer.key().as_ref(),
+          retirement_id.to_le_bytes().as_ref(),
+      ],
+      bump
     )]
     pub nft_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
@@ -72,7 +81,7 @@ pub struct RetireToken<'info> {
 }
 
 impl<'info> RetireToken<'info> {
-    pub fn handler(&mut self, amount: u64) -> Result<()> {
+    pub fn handler(&mut self, amount: u64, retirement_id: u64) -> Result<CreditsRetired> {
         require!(amount > 0, MyErrorCode::InvalidAmount);
         let consumer_token_account = &self.consumer_token_account;
         require!(
@@ -83,17 +92,28 @@ impl<'info> RetireToken<'info> {
         let name = RETIRED_CREDITS_CERT_NAME.to_string();
         let symbol = RETIRED_CREDITS_CERT_SYMBOL.to_string();
         let uri = "".to_string();
+        // verifiers re-derive the certificate address from these
+        let fields = vec![
+            (RETIRED_CREDITS_KEY.to_string(), amount.to_string()),
+            (RETIRED_BY_KEY.to_string(), self.consumer.key().to_string()),
+            (RETIREMENT_ID_KEY.to_string(), retirement_id.to_string()),
+        ];
 
         self.update_account_lamports_by_extensions(
             name.clone(),
             symbol.clone(),
             uri.clone(),
-            amount,
+            fields.clone(),
             &[ExtensionType::MetadataPointer],
         )?;
-        self.init_nft_metadata(name, symbol, uri, amount)?;
+        self.init_nft_metadata(name, symbol, uri, fields)?;
         self.mint_and_send_nft()?;
-        Ok(())
+        Ok(CreditsRetired {
+            rwa_mint: self.mint.key(),
+            consumer: self.consumer.key(),
+            amount,
+            certificate_mint: self.nft_mint.key(),
+        })
     }
 
     fn mint_and_send_nft(&mut self) -> Result<()> {
@@ -141,7 +161,7 @@ impl<'info> RetireToken<'info> {
         name: String,
         symbol: String,
         uri: String,
-        amount: u64,
+        fields: Vec<(String, String)>,
     ) -> Result<()> {
         let mint_key = self.mint.key();
         let seeds = &[
@@ -169,19 +189,21 @@ impl<'info> RetireToken<'info> {
             uri,
         )?;
 
-        token_metadata_update_field(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                TokenMetadataUpdateField {
-                    metadata: self.nft_mint.to_account_info(),
-                    update_authority: self.mint_authority.to_account_info(),
-                    program_id: self.token_program.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            Field::Key(RETIRED_CREDITS_KEY.to_string()),
-            amount.to_string(),
-        )?;
+        for (key, value) in fields {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        metadata: self.nft_mint.to_account_info(),
+                        update_authority: self.mint_authority.to_account_info(),
+                        program_id: self.token_program.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                Field::Key(key),
+                value,
+            )?;
+        }
 
         Ok(())
     }
@@ -191,7 +213,7 @@ impl<'info> RetireToken<'info> {
         name: String,
         symbol: String,
         uri: String,
-        amount: u64,
+        fields: Vec<(String, String)>,
         extension: &[ExtensionType],
     ) -> Result<()> {
         let token_metadata = TokenMetadata {
@@ -200,7 +222,7 @@ impl<'info> RetireToken<'info> {
             name: name.to_string(),
             symbol: symbol.to_string(),
             uri: uri.to_string(),
-            additional_metadata: vec![(RETIRED_CREDITS_KEY.to_string(), amount.to_string())],
+            additional_metadata: fields,
         };
 
         let space =
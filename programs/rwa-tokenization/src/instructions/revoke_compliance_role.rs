@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{ComplianceRole, GovernanceConfig, COMPLIANCE_ROLE_SEED, GOVERNANCE_CONFIG_SEED};
+
+#[derive(Accounts)]
+pub struct RevokeComplianceRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [COMPLIANCE_ROLE_SEED, compliance_role.officer.as_ref()],
+        bump = compliance_role.bump,
+    )]
+    pub compliance_role: Box<Account<'info, ComplianceRole>>,
+}
+
+impl<'info> RevokeComplianceRole<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+use token_transfer_hook::{
+    cpi::accounts::SetAllowedAccount as HookSetAllowedAccount, program::TokenTransferHook,
+};
+
+use crate::{
+    error::MyErrorCode, GovernanceConfig, MintAuthority, GOVERNANCE_CONFIG_SEED,
+    MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetAllowedAccount<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        constraint = mint_authority.transfer_hook == Some(transfer_hook_program.key()) @ MyErrorCode::MissingTransferHookAccounts,
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        token::mint = rwa_mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: TransferPolicy of the mint, validated by the transfer hook program
+    #[account(mut)]
+    pub transfer_policy: UncheckedAccount<'info>,
+    pub transfer_hook_program: Program<'info, TokenTransferHook>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAllowedAccount<'info> {
+    pub fn handler(&mut self, is_allowed: bool) -> Result<()> {
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_transfer_hook::cpi::set_allowed_account(
+            CpiContext::new_with_signer(
+                self.transfer_hook_program.to_account_info(),
+                HookSetAllowedAccount {
+                    payer: self.authority.to_account_info(),
+                    authority: self.mint_authority.to_account_info(),
+                    transfer_policy: self.transfer_policy.to_account_info(),
+                    token_account: self.token_account.to_account_info(),
+                    mint: self.rwa_mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            is_allowed,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{token_2022::Token2022, token_interface::Mint};
+use token_transfer_hook::Jurisdiction;
+
+use crate::{
+    error::MyErrorCode, ConsumerController, GovernanceConfig, CONSUMER_NFT_SEED,
+    GOVERNANCE_CONFIG_SEED,
+};
+
+// ConsumerController fields of accounts issued before jurisdictions were stored
+#[derive(AnchorDeserialize)]
+struct LegacyConsumerController {
+    rwa_mint: Pubkey,
+    mint: Pubkey,
+    user: Pubkey,
+    bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct SetConsumerJurisdiction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: Wallet holding the consumer certificate
+    pub consumer: UncheckedAccount<'info>,
+    /// CHECK: Consumer cert nft of the wallet, only used to derive the controller
+    #[account(
+        seeds = [CONSUMER_NFT_SEED, rwa_mint.key().as_ref(), consumer.key.as_ref()],
+        bump
+    )]
+    pub consumer_nft_mint: UncheckedAccount<'info>,
+    /// CHECK: ConsumerController of the cert, read by hand since legacy accounts are too short to
+    /// deserialize
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [CONSUMER_NFT_SEED, consumer_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub consumer_controller: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetConsumerJurisdiction<'info> {
+    pub fn handler(&mut self, jurisdiction: Jurisdiction) -> Result<()> {
+        require!(jurisdiction.is_valid(), MyErrorCode::InvalidJurisdiction);
+
+        let consumer_controller = self.consumer_controller.to_account_info();
+        let legacy = {
+            let data = consumer_controller.try_borrow_data()?;
+            require!(
+                data.starts_with(ConsumerController::DISCRIMINATOR),
+                MyErrorCode::InvalidConsumerCert
+            );
+            LegacyConsumerController::deserialize(&mut &data[8..])?
+        };
+        require_keys_eq!(
+            legacy.rwa_mint,
+            self.rwa_mint.key(),
+            MyErrorCode::InvalidConsumerCert
+        );
+
+        // legacy accounts end right before the jurisdiction, grow them to the current size
+        let space = 8 + ConsumerController::INIT_SPACE;
+        if consumer_controller.data_len() < space {
+            let lamports = Rent::get()?
+                .minimum_balance(space)
+                .saturating_sub(consumer_controller.lamports());
+            if lamports > 0 {
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.authority.to_account_info(),
+                            to: consumer_controller.clone(),
+                        },
+                    ),
+                    lamports,
+                )?;
+            }
+            consumer_controller.realloc(space, false)?;
+        }
+
+        ConsumerController {
+            rwa_mint: legacy.rwa_mint,
+            mint: legacy.mint,
+            user: legacy.user,
+            bump: legacy.bump,
+            jurisdiction,
+        }
+        .try_serialize(&mut &mut consumer_controller.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{thaw_account, ThawAccount, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    error::MyErrorCode, ConsumerController, MintAuthority, ACCOUNT_HOLD_SEED, CONSUMER_NFT_SEED,
+    MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ThawForConsumer<'info> {
+    pub consumer: Signer<'info>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mint::token_program = token_program,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::token_program = token_program,
+        token::mint = rwa_mint,
+        token::authority = consumer,
+    )]
+    pub consumer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        constraint = consumer_controller.rwa_mint == rwa_mint.key() @ MyErrorCode::InvalidConsumerCert,
+        constraint = consumer_controller.user == consumer.key() @ MyErrorCode::InvalidConsumerCert,
+        seeds = [CONSUMER_NFT_SEED, consumer_nft_mint.key().as_ref()],
+        bump = consumer_controller.bump
+    )]
+    pub consumer_controller: Box<Account<'info, ConsumerController>>,
+    #[account(
+        mint::token_program = token_program,
+        mint::decimals = 0,
+        constraint = consumer_nft_mint.supply == 1 @ MyErrorCode::InvalidConsumerCert,
+        seeds = [CONSUMER_NFT_SEED, rwa_mint.key().as_ref(), consumer.key.as_ref()],
+        bump
+    )]
+    pub consumer_nft_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        associated_token::token_program = token_program,
+        associated_token::mint = consumer_nft_mint,
+        associated_token::authority = consumer,
+        constraint = consumer_nft_token_account.amount == 1 @ MyErrorCode::InvalidConsumerCert,
+    )]
+    pub consumer_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Compliance hold marker, must not exist for a self-service thaw
+    #[account(
+        seeds = [ACCOUNT_HOLD_SEED, consumer_token_account.key().as_ref()],
+        bump
+    )]
+    pub account_hold: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> ThawForConsumer<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        require!(
+            self.account_hold.data_is_empty(),
+            MyErrorCode::AccountOnHold
+        );
+
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        thaw_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            ThawAccount {
+                account: self.consumer_token_account.to_account_info(),
+                mint: self.rwa_mint.to_account_info(),
+                authority: self.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        Ok(())
+    }
+}
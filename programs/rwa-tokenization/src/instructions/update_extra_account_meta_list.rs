@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface::Mint};
+use token_transfer_hook::{
+    cpi::accounts::UpdateExtraAccountMetaList as HookUpdateExtraAccountMetaList,
+    program::TokenTransferHook,
+};
+
+use crate::{
+    error::MyErrorCode, program::RwaTokenization, GovernanceConfig, MintAuthority,
+    GOVERNANCE_CONFIG_SEED, MINT_AUTHORITY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        constraint = mint_authority.transfer_hook == Some(transfer_hook_program.key()) @ MyErrorCode::MissingTransferHookAccounts,
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: ExtraAccountMetaList Account of the transfer hook, rewritten via CPI
+    #[account(mut)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub rwa_program: Program<'info, RwaTokenization>,
+    pub transfer_hook_program: Program<'info, TokenTransferHook>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateExtraAccountMetaList<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // rolls the current hook checks out to a mint created with an older account list
+        token_transfer_hook::cpi::update_extra_account_meta_list(CpiContext::new_with_signer(
+            self.transfer_hook_program.to_account_info(),
+            HookUpdateExtraAccountMetaList {
+                payer: self.authority.to_account_info(),
+                authority: self.mint_authority.to_account_info(),
+                extra_account_meta_list: self.extra_account_meta_list.to_account_info(),
+                mint: self.rwa_mint.to_account_info(),
+                rwa_program: self.rwa_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        Ok(())
+    }
+}
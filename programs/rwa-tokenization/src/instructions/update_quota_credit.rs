@@ -7,8 +7,9 @@ use anchor_spl::{
     },
 };
 
-use crate::{MinterController, AVAILABLE_CREDITS_KEY, MINTER_NFT_SEED};
+use crate::{MinterController, QuotaUpdated, AVAILABLE_CREDITS_KEY, MINTER_NFT_SEED};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateQuotaCredit<'info> {
     #[account(mut)]
@@ -38,7 +39,7 @@ pub struct UpdateQuotaCredit<'info> {
 }
 
 impl<'info> UpdateQuotaCredit<'info> {
-    pub fn handler(&mut self, new_credit: u64) -> Result<()> {
+    pub fn handler(&mut self, new_credit: u64) -> Result<QuotaUpdated> {
         let mint_key = self.mint.key();
         let seeds = &[
             MINTER_NFT_SEED,
@@ -59,6 +60,11 @@ impl<'info> UpdateQuotaCredit<'info> {
             Field::Key(AVAILABLE_CREDITS_KEY.to_string()),
             new_credit.to_string(),
         )?;
-        Ok(())
+        Ok(QuotaUpdated {
+            rwa_mint: self.permissioned_mint.key(),
+            minter: self.receiver.key(),
+            nft_mint: self.mint.key(),
+            available_credits: new_credit,
+        })
     }
 }
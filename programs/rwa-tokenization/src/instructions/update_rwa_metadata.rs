@@ -0,0 +1,134 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{
+        spl_token_metadata_interface::{self, state::Field},
+        token_metadata_update_field, Mint, TokenMetadataUpdateField,
+    },
+};
+
+use crate::{
+    update_account_lamports_to_minimum_balance, GovernanceConfig, MintAuthority,
+    GOVERNANCE_CONFIG_SEED, MINT_AUTHORITY_SEED,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MetadataField {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRwaMetadata<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [GOVERNANCE_CONFIG_SEED],
+        bump = config_account.bump,
+    )]
+    pub config_account: Box<Account<'info, GovernanceConfig>>,
+    #[account(
+        constraint = mint_authority.mint == rwa_mint.key(),
+        seeds = [MINT_AUTHORITY_SEED, rwa_mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub rwa_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateRwaMetadata<'info> {
+    pub fn handler(
+        &mut self,
+        name: Option<String>,
+        uri: Option<String>,
+        fields: Vec<MetadataField>,
+        remove_keys: Vec<String>,
+    ) -> Result<()> {
+        let mut updates = Vec::with_capacity(fields.len() + 2);
+        if let Some(name) = name {
+            updates.push((Field::Name, name));
+        }
+        if let Some(uri) = uri {
+            updates.push((Field::Uri, uri));
+        }
+        updates.extend(
+            fields
+                .into_iter()
+                .map(|field| (Field::Key(field.key), field.value)),
+        );
+
+        for (field, value) in updates {
+            self.update_field(field, value)?;
+        }
+        for key in remove_keys {
+            self.remove_key(key)?;
+        }
+
+        // token metadata reallocs the mint in place, top up rent for the new size
+        update_account_lamports_to_minimum_balance(
+            self.rwa_mint.to_account_info(),
+            self.authority.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+        Ok(())
+    }
+
+    fn update_field(&self, field: Field, value: String) -> Result<()> {
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    metadata: self.rwa_mint.to_account_info(),
+                    update_authority: self.mint_authority.to_account_info(),
+                    program_id: self.token_program.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            field,
+            value,
+        )?;
+        Ok(())
+    }
+
+    fn remove_key(&self, key: String) -> Result<()> {
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let ix = spl_token_metadata_interface::instruction::remove_key(
+            self.token_program.key,
+            &self.rwa_mint.key(),
+            &self.mint_authority.key(),
+            key,
+            false,
+        );
+        invoke_signed(
+            &ix,
+            &[
+                self.rwa_mint.to_account_info(),
+                self.mint_authority.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 use anchor_lang::prelude::*;
+use token_transfer_hook::{DenyReason, Jurisdiction, JurisdictionRule, TransferPolicyParams};
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use utils::*;
@@ -19,7 +22,9 @@ pub mod rwa_tokenization {
     use super::*;
 
     pub fn initialize(ctx: Context<InitializeConfig>) -> Result<()> {
-        ctx.accounts.handler(&ctx.bumps)
+        let event = ctx.accounts.handler(&ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn issue_minter_cert(
@@ -28,11 +33,15 @@ pub mod rwa_tokenization {
         symbol: String,
         uri: String,
     ) -> Result<()> {
-        ctx.accounts.handler(name, symbol, uri, &ctx.bumps)
+        let event = ctx.accounts.handler(name, symbol, uri, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn update_quota_credit(ctx: Context<UpdateQuotaCredit>, new_credit: u64) -> Result<()> {
-        ctx.accounts.handler(new_credit)
+        let event = ctx.accounts.handler(new_credit)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
     pub fn issue_consumer_cert(
@@ -40,10 +49,16 @@ pub mod rwa_tokenization {
         name: String,
         symbol: String,
         uri: String,
+        jurisdiction: Jurisdiction,
     ) -> Result<()> {
-        ctx.accounts.handler(name, symbol, uri, &ctx.bumps)
+        let event = ctx
+            .accounts
+            .handler(name, symbol, uri, jurisdiction, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init_rwa_token(
         ctx: Context<InitRwaToken>,
         name: String,
@@ -54,8 +69,10 @@ pub mod rwa_tokenization {
         has_fee: bool,  // whether the mint use transfer fee extension
         transfer_fee_basis_points: Option<u16>,
         maximum_fee: Option<u64>,
+        default_frozen: bool, // whether new token accounts start frozen until KYC thaw
+        has_clawback: bool,   // whether the mint authority is permanent delegate for clawback
     ) -> Result<()> {
-        ctx.accounts.handler(
+        let event = ctx.accounts.handler(
             name,
             symbol,
             decimals,
@@ -64,15 +81,152 @@ pub mod rwa_tokenization {
             has_fee,
             transfer_fee_basis_points,
             maximum_fee,
+            default_frozen,
+            has_clawback,
+            &ctx.bumps,
+        )?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn mint_rwa_token(
+        ctx: Context<MintRwaToken>,
+        amount: u64, // whole credits, minted as amount * 10^decimals base units
+        lockup_until: Option<i64>,
+        linear_vesting: bool,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .handler(amount, lockup_until, linear_vesting, &ctx.bumps)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    // amount in base units
+    pub fn retire_token(ctx: Context<RetireToken>, amount: u64, retirement_id: u64) -> Result<()> {
+        let event = ctx.accounts.handler(amount, retirement_id)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    pub fn thaw_for_consumer(ctx: Context<ThawForConsumer>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        ctx.accounts.handler(&ctx.bumps)
+    }
+
+    pub fn release_hold(ctx: Context<ReleaseHold>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn force_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, ForceTransfer<'info>>,
+        action_id: u64,
+        amount: u64, // base units, as for retire_token
+        reason: String,
+        document_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.handler(
+            action_id,
+            amount,
+            reason,
+            document_hash,
+            ctx.remaining_accounts,
             &ctx.bumps,
         )
     }
 
-    pub fn mint_rwa_token(ctx: Context<MintRwaToken>, amount: u64) -> Result<()> {
-        ctx.accounts.handler(amount)
+    pub fn force_burn(
+        ctx: Context<ForceBurn>,
+        action_id: u64,
+        amount: u64, // base units, as for retire_token
+        reason: String,
+        document_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .handler(action_id, amount, reason, document_hash, &ctx.bumps)
+    }
+
+    pub fn migrate_minter_holder<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateMinterHolder<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    pub fn migrate_consumer_holder<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateConsumerHolder<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.handler(ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    pub fn close_rwa_token(ctx: Context<CloseRwaToken>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn update_rwa_metadata(
+        ctx: Context<UpdateRwaMetadata>,
+        name: Option<String>,
+        uri: Option<String>,
+        fields: Vec<MetadataField>,
+        remove_keys: Vec<String>,
+    ) -> Result<()> {
+        ctx.accounts.handler(name, uri, fields, remove_keys)
+    }
+
+    pub fn set_transfer_policy(
+        ctx: Context<SetTransferPolicy>,
+        params: TransferPolicyParams,
+    ) -> Result<()> {
+        ctx.accounts.handler(params)
+    }
+
+    pub fn set_allowed_account(ctx: Context<SetAllowedAccount>, is_allowed: bool) -> Result<()> {
+        ctx.accounts.handler(is_allowed)
     }
 
-    pub fn retire_token(ctx: Context<RetireToken>, amount: u64) -> Result<()> {
-        ctx.accounts.handler(amount)
+    pub fn set_allowed_program_owner(
+        ctx: Context<SetAllowedProgramOwner>,
+        program_id: Pubkey,
+        is_allowed: bool,
+    ) -> Result<()> {
+        ctx.accounts.handler(program_id, is_allowed)
+    }
+
+    pub fn set_jurisdiction_rules(
+        ctx: Context<SetJurisdictionRules>,
+        default_allowed: bool,
+        rules: Vec<JurisdictionRule>,
+    ) -> Result<()> {
+        ctx.accounts.handler(default_allowed, rules)
+    }
+
+    pub fn grant_compliance_role(ctx: Context<GrantComplianceRole>) -> Result<()> {
+        ctx.accounts.handler(&ctx.bumps)
+    }
+
+    pub fn revoke_compliance_role(ctx: Context<RevokeComplianceRole>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn add_to_denylist(ctx: Context<AddToDenylist>, reason: DenyReason) -> Result<()> {
+        ctx.accounts.handler(reason, &ctx.bumps)
+    }
+
+    pub fn remove_from_denylist(ctx: Context<RemoveFromDenylist>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+        ctx.accounts.handler()
+    }
+
+    // also stores the jurisdiction of consumers issued before it was recorded
+    pub fn set_consumer_jurisdiction(
+        ctx: Context<SetConsumerJurisdiction>,
+        jurisdiction: Jurisdiction,
+    ) -> Result<()> {
+        ctx.accounts.handler(jurisdiction)
     }
 }
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AccountHold {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    // false when the account was already frozen, e.g. by the mint's default account state
+    pub froze_account: bool,
+    pub bump: u8,
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_COMPLIANCE_REASON_LEN;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ComplianceActionKind {
+    ForceTransfer,
+    ForceBurn,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceAction {
+    pub mint: Pubkey,
+    pub action_id: u64,
+    pub kind: ComplianceActionKind,
+    pub authority: Pubkey,
+    pub source: Pubkey,
+    pub destination: Option<Pubkey>,
+    pub amount: u64,
+    #[max_len(MAX_COMPLIANCE_REASON_LEN)]
+    pub reason: String,
+    pub document_hash: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+// granted by governance, allows the officer to manage the denylist
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceRole {
+    pub officer: Pubkey,
+    pub granted_by: Pubkey,
+    pub bump: u8,
+}
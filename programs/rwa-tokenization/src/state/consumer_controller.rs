@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use token_transfer_hook::Jurisdiction;
 
 #[account]
 #[derive(InitSpace)]
@@ -7,4 +8,6 @@ pub struct ConsumerController {
     pub mint: Pubkey,
     pub user: Pubkey,
     pub bump: u8,
+    // must stay right after bump, the transfer hook reads it at a fixed offset
+    pub jurisdiction: Jurisdiction,
 }
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use token_transfer_hook::DenyReason;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Denylist {
+    // must stay the first field, the transfer hook reads it at a fixed offset
+    pub reason: DenyReason,
+    pub wallet: Pubkey,
+    pub officer: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
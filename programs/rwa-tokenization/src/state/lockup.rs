@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use token_transfer_hook::LockupSchedule;
+
+use crate::error::MyErrorCode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Lockup {
+    // must stay the first field, the transfer hook reads it at a fixed offset
+    pub schedule: LockupSchedule,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub bump: u8,
+}
+
+impl Lockup {
+    // tokens still locked are carried into the new schedule, which must unlock the same way so
+    // cliff-locked tokens never start vesting before their cliff
+    pub fn lock(&mut self, amount: u64, now: i64, unlock_at: i64, is_linear: bool) -> Result<()> {
+        let still_locked = self.schedule.locked_at(now);
+        let unlock_at = if still_locked > 0 {
+            require!(
+                self.schedule.is_linear == is_linear,
+                MyErrorCode::LockupScheduleMismatch
+            );
+            self.schedule.unlock_at.max(unlock_at)
+        } else {
+            unlock_at
+        };
+        self.schedule = LockupSchedule {
+            locked_amount: still_locked
+                .checked_add(amount)
+                .ok_or(MyErrorCode::Overflow)?,
+            start_at: now,
+            unlock_at,
+            is_linear,
+        };
+        Ok(())
+    }
+}
@@ -10,3 +10,18 @@ pub use minter_controller::*;
 
 pub mod consumer_controller;
 pub use consumer_controller::*;
+
+pub mod account_hold;
+pub use account_hold::*;
+
+pub mod compliance_action;
+pub use compliance_action::*;
+
+pub mod compliance_role;
+pub use compliance_role::*;
+
+pub mod denylist;
+pub use denylist::*;
+
+pub mod lockup;
+pub use lockup::*;
@@ -1,5 +1,25 @@
 use anchor_lang::{prelude::*, system_program};
-use anchor_spl::token_2022::spl_token_2022::{self, extension::ExtensionType};
+use anchor_spl::token_2022::{
+    burn_checked, close_account, freeze_account,
+    spl_token_2022::{
+        self,
+        extension::{
+            permanent_delegate::PermanentDelegate, BaseStateWithExtensions, ExtensionType,
+            StateWithExtensions,
+        },
+        instruction::AuthorityType,
+    },
+    thaw_account, BurnChecked, CloseAccount, FreezeAccount, ThawAccount,
+};
+use anchor_spl::token_interface::{
+    mint_to, set_authority,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+    token_metadata_initialize, token_metadata_update_field, Mint, MintTo, SetAuthority,
+    TokenAccount, TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+
+use crate::{error::MyErrorCode, Lockup, MINT_AUTHORITY_SEED};
 
 pub fn update_account_minimum_lamports<'info>(
     account: AccountInfo<'info>,
@@ -33,7 +53,9 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let extra_lamports = Rent::get()?.minimum_balance(account.data_len()) - account.get_lamports();
+    let extra_lamports = Rent::get()?
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.get_lamports());
     if extra_lamports > 0 {
         system_program::transfer(
             CpiContext::new(
@@ -49,32 +71,394 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
-pub fn get_mint_space_with_extensions(is_close: bool, has_fee: bool) -> Result<usize> {
-    msg!("is_close: {}, has_fee: {}", is_close, has_fee);
-    let extensions: &[ExtensionType] = match (is_close, has_fee) {
-        (true, true) => &[
-            ExtensionType::MetadataPointer,
-            ExtensionType::MintCloseAuthority,
-            ExtensionType::TransferHook,
-            ExtensionType::TransferFeeConfig,
-        ],
-        (true, false) => &[
-            ExtensionType::MetadataPointer,
-            ExtensionType::MintCloseAuthority,
-            ExtensionType::TransferHook,
-        ],
-        (false, true) => &[
-            ExtensionType::MetadataPointer,
-            ExtensionType::MintCloseAuthority,
-            ExtensionType::TransferFeeConfig,
-        ],
-        (false, false) => &[
-            ExtensionType::MetadataPointer,
-            ExtensionType::MintCloseAuthority,
-        ],
-    };
+pub fn thaw_token_account<'info>(
+    token_program: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    thaw_account(CpiContext::new_with_signer(
+        token_program,
+        ThawAccount {
+            account,
+            mint,
+            authority,
+        },
+        signer_seeds,
+    ))
+}
+
+pub fn freeze_token_account<'info>(
+    token_program: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    freeze_account(CpiContext::new_with_signer(
+        token_program,
+        FreezeAccount {
+            account,
+            mint,
+            authority,
+        },
+        signer_seeds,
+    ))
+}
+
+// Move a holder balance as the mint's permanent delegate, thawing frozen accounts
+// (holds or default frozen state) only for the duration of the transfer.
+#[allow(clippy::too_many_arguments)]
+pub fn clawback_transfer<'info>(
+    token_program: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    source: &InterfaceAccount<'info, TokenAccount>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    permanent_delegate: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let frozen_accounts = [source, destination]
+        .into_iter()
+        .filter(|account| account.is_frozen())
+        .map(|account| account.to_account_info())
+        .collect::<Vec<_>>();
+
+    for account in frozen_accounts.iter() {
+        thaw_token_account(
+            token_program.clone(),
+            account.clone(),
+            mint.to_account_info(),
+            permanent_delegate.clone(),
+            signer_seeds,
+        )?;
+    }
+
+    // remaining accounts carry the transfer hook extra accounts when the mint is closed
+    spl_token_2022::onchain::invoke_transfer_checked(
+        token_program.key,
+        source.to_account_info(),
+        mint.to_account_info(),
+        destination.to_account_info(),
+        permanent_delegate.clone(),
+        remaining_accounts,
+        amount,
+        mint.decimals,
+        signer_seeds,
+    )?;
+
+    for account in frozen_accounts.into_iter() {
+        freeze_token_account(
+            token_program.clone(),
+            account,
+            mint.to_account_info(),
+            permanent_delegate.clone(),
+            signer_seeds,
+        )?;
+    }
+    Ok(())
+}
+
+// Accounts of migrate_minter_holder and migrate_consumer_holder, which only differ in the
+// certificate seed and controller type.
+pub struct HolderMigration<'a, 'info> {
+    pub authority: AccountInfo<'info>,
+    pub rwa_mint: &'a InterfaceAccount<'info, Mint>,
+    pub mint_authority: AccountInfo<'info>,
+    pub mint_authority_bump: u8,
+    pub nft_seed: &'static [u8],
+    pub old_controller: AccountInfo<'info>,
+    pub old_controller_bump: u8,
+    pub old_nft_mint: &'a InterfaceAccount<'info, Mint>,
+    pub old_nft_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub new_controller: AccountInfo<'info>,
+    pub new_controller_bump: u8,
+    pub new_nft_mint: &'a InterfaceAccount<'info, Mint>,
+    pub new_nft_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub old_rwa_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub new_rwa_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub old_lockup: Option<&'a mut Account<'info, Lockup>>,
+    pub new_lockup: Option<&'a mut Account<'info, Lockup>>,
+    pub new_lockup_bump: Option<u8>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> HolderMigration<'_, 'info> {
+    // the new controller account is initialized by the caller
+    pub fn migrate(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let old_metadata = get_token_metadata(&self.old_nft_mint.to_account_info())?;
+
+        self.retire_old_cert()?;
+        self.update_account_lamports_by_metadata(&old_metadata)?;
+        self.init_nft_metadata(old_metadata)?;
+        self.mint_and_send_nft()?;
+        self.move_rwa_balance(remaining_accounts)
+    }
+
+    // burn the lost certificate as permanent delegate and close its mint
+    fn retire_old_cert(&self) -> Result<()> {
+        let mint_key = self.old_nft_mint.key();
+        let seeds = &[
+            self.nft_seed,
+            mint_key.as_ref(),
+            &[self.old_controller_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        if self.old_nft_token_account.amount > 0 {
+            burn_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.clone(),
+                    BurnChecked {
+                        from: self.old_nft_token_account.to_account_info(),
+                        authority: self.old_controller.clone(),
+                        mint: self.old_nft_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                self.old_nft_token_account.amount,
+                0,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
+            CloseAccount {
+                account: self.old_nft_mint.to_account_info(),
+                destination: self.authority.clone(),
+                authority: self.old_controller.clone(),
+            },
+            signer_seeds,
+        ))?;
+        Ok(())
+    }
+
+    fn mint_and_send_nft(&self) -> Result<()> {
+        let mint_key = self.new_nft_mint.key();
+        let seeds = &[
+            self.nft_seed,
+            mint_key.as_ref(),
+            &[self.new_controller_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // nint just 1 token, because it's a NFT
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                MintTo {
+                    mint: self.new_nft_mint.to_account_info(),
+                    to: self.new_nft_token_account.to_account_info(),
+                    authority: self.new_controller.clone(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        // Freeze mint authority to prevent minting more tokens
+        set_authority(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                SetAuthority {
+                    current_authority: self.new_controller.clone(),
+                    account_or_mint: self.new_nft_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        Ok(())
+    }
+
+    // carry name, symbol, uri and the additional fields (quota counters) over to the new certificate
+    fn init_nft_metadata(&self, old_metadata: TokenMetadata) -> Result<()> {
+        let mint_key = self.new_nft_mint.key();
+        let seeds = &[
+            self.nft_seed,
+            mint_key.as_ref(),
+            &[self.new_controller_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                TokenMetadataInitialize {
+                    mint: self.new_nft_mint.to_account_info(),
+                    program_id: self.token_program.clone(),
+                    mint_authority: self.new_controller.clone(),
+                    update_authority: self.new_controller.clone(),
+                    metadata: self.new_nft_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            old_metadata.name,
+            old_metadata.symbol,
+            old_metadata.uri,
+        )?;
+
+        for (key, value) in old_metadata.additional_metadata {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.clone(),
+                    TokenMetadataUpdateField {
+                        metadata: self.new_nft_mint.to_account_info(),
+                        update_authority: self.new_controller.clone(),
+                        program_id: self.token_program.clone(),
+                    },
+                    signer_seeds,
+                ),
+                Field::Key(key),
+                value,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn update_account_lamports_by_metadata(&self, old_metadata: &TokenMetadata) -> Result<()> {
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(self.new_controller.key()),
+            mint: self.new_nft_mint.key(),
+            name: old_metadata.name.clone(),
+            symbol: old_metadata.symbol.clone(),
+            uri: old_metadata.uri.clone(),
+            additional_metadata: old_metadata.additional_metadata.clone(),
+        };
+
+        let meta_data_space = token_metadata.tlv_size_of()?;
+
+        update_account_minimum_lamports(
+            self.new_nft_mint.to_account_info(),
+            self.authority.clone(),
+            self.system_program.clone(),
+            meta_data_space,
+        )?;
+
+        Ok(())
+    }
+
+    // with clawback enabled the whole RWA balance follows the holder to the new wallet
+    fn move_rwa_balance(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let (Some(old_rwa_token_account), Some(new_rwa_token_account)) =
+            (self.old_rwa_token_account, self.new_rwa_token_account)
+        else {
+            return Ok(());
+        };
+        if old_rwa_token_account.amount == 0
+            || !is_permanent_delegate(&self.rwa_mint.to_account_info(), self.mint_authority.key)?
+        {
+            return Ok(());
+        }
+
+        let mint_key = self.rwa_mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[self.mint_authority_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        clawback_transfer(
+            self.token_program.clone(),
+            self.rwa_mint,
+            old_rwa_token_account,
+            new_rwa_token_account,
+            self.mint_authority.clone(),
+            remaining_accounts,
+            old_rwa_token_account.amount,
+            signer_seeds,
+        )?;
+        self.move_lockup(new_rwa_token_account.owner)
+    }
+
+    // tokens still locked for the old wallet stay locked for the new one
+    fn move_lockup(&mut self, new_wallet: Pubkey) -> Result<()> {
+        let Some(old_lockup) = self.old_lockup.as_deref_mut() else {
+            return Ok(());
+        };
+        let now = Clock::get()?.unix_timestamp;
+        let still_locked = old_lockup.schedule.locked_at(now);
+        if still_locked == 0 {
+            return Ok(());
+        }
+        let new_lockup = self
+            .new_lockup
+            .as_deref_mut()
+            .ok_or(MyErrorCode::MissingLockupAccount)?;
+        let bump = self
+            .new_lockup_bump
+            .ok_or(MyErrorCode::MissingLockupAccount)?;
+
+        new_lockup.lock(
+            still_locked,
+            now,
+            old_lockup.schedule.unlock_at,
+            old_lockup.schedule.is_linear,
+        )?;
+        new_lockup.mint = self.rwa_mint.key();
+        new_lockup.holder = new_wallet;
+        new_lockup.bump = bump;
+        old_lockup.schedule.locked_amount = 0;
+        Ok(())
+    }
+}
+
+pub fn get_token_metadata(mint: &AccountInfo) -> Result<TokenMetadata> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    Ok(mint_with_extension.get_variable_len_extension::<TokenMetadata>()?)
+}
+
+pub fn is_permanent_delegate(mint: &AccountInfo, delegate: &Pubkey) -> Result<bool> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    Ok(mint_with_extension
+        .get_extension::<PermanentDelegate>()
+        .ok()
+        .and_then(|extension| Option::<Pubkey>::from(extension.delegate))
+        == Some(*delegate))
+}
+
+pub fn get_mint_space_with_extensions(
+    is_close: bool,
+    has_fee: bool,
+    default_frozen: bool,
+    has_clawback: bool,
+) -> Result<usize> {
+    msg!(
+        "is_close: {}, has_fee: {}, default_frozen: {}, has_clawback: {}",
+        is_close,
+        has_fee,
+        default_frozen,
+        has_clawback
+    );
+    let mut extensions = vec![
+        ExtensionType::MetadataPointer,
+        ExtensionType::MintCloseAuthority,
+    ];
+    if is_close {
+        extensions.push(ExtensionType::TransferHook);
+    }
+    if has_fee {
+        extensions.push(ExtensionType::TransferFeeConfig);
+    }
+    if default_frozen {
+        extensions.push(ExtensionType::DefaultAccountState);
+    }
+    if has_clawback {
+        extensions.push(ExtensionType::PermanentDelegate);
+    }
 
     Ok(ExtensionType::try_calculate_account_len::<
         spl_token_2022::state::Mint,
-    >(extensions)?)
+    >(&extensions)?)
 }
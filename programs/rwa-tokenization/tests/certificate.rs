@@ -0,0 +1,304 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::{
+    token_2022::{
+        self,
+        spl_token_2022::{
+            extension::{
+                metadata_pointer::{self, MetadataPointer},
+                BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+            },
+            instruction::{self as token_instruction, AuthorityType},
+            state::Mint,
+        },
+    },
+    token_interface::{
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::{instruction as metadata_instruction, state::Field},
+    },
+};
+use common::*;
+use rwa_tokenization::{
+    RETIRED_BY_KEY, RETIRED_CREDITS_CERT_NAME, RETIRED_CREDITS_CERT_SYMBOL, RETIRED_CREDITS_KEY,
+    RETIREMENT_ID_KEY,
+};
+use rwa_tokenization_client::{
+    instructions, verify_retirement_certificate, CertificateVerification, FetchedAccount,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+    system_instruction,
+};
+
+const RETIRED: u64 = 2_500;
+
+async fn retire(context: &mut ProgramTestContext, rwa: &RwaFixture, amount: u64) -> Pubkey {
+    let alice = Keypair::new();
+    rwa.mint(context, &alice.pubkey(), 50).await;
+    send(
+        context,
+        &[retire_token_ix(
+            &rwa.admin.pubkey(),
+            &alice.pubkey(),
+            &rwa.rwa_mint,
+            0,
+            amount,
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    retirement_certificate_pda(&rwa.rwa_mint, &alice.pubkey(), 0)
+}
+
+async fn verify(
+    context: &mut ProgramTestContext,
+    certificate_mint: &Pubkey,
+    rwa_mint: &Pubkey,
+) -> CertificateVerification {
+    let account = context
+        .banks_client
+        .get_account(*certificate_mint)
+        .await
+        .unwrap();
+    verify_retirement_certificate(certificate_mint, rwa_mint, |address| {
+        account
+            .as_ref()
+            .filter(|_| address == certificate_mint)
+            .map(|account| FetchedAccount {
+                owner: account.owner,
+                data: account.data.clone(),
+            })
+    })
+}
+
+fn failed(verification: &CertificateVerification) -> Vec<&str> {
+    verification
+        .checks
+        .iter()
+        .filter(|check| !check.passed)
+        .map(|check| check.name)
+        .collect()
+}
+
+// a keypair mint copying everything retire_token writes, handing its authorities to the
+// MintAuthority PDA once the metadata is filled in
+fn forge_certificate_ixs(
+    forger: &Pubkey,
+    certificate: &Pubkey,
+    rwa_mint: &Pubkey,
+    consumer: &Pubkey,
+    rent: u64,
+) -> [Vec<Instruction>; 2] {
+    let authority = mint_authority_pda(rwa_mint);
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+        ExtensionType::MetadataPointer,
+        ExtensionType::MintCloseAuthority,
+        ExtensionType::PermanentDelegate,
+    ])
+    .unwrap();
+    let fields = [
+        (RETIRED_CREDITS_KEY, RETIRED.to_string()),
+        (RETIRED_BY_KEY, consumer.to_string()),
+        (RETIREMENT_ID_KEY, "0".to_string()),
+    ];
+    let mut create = vec![
+        system_instruction::create_account(
+            forger,
+            certificate,
+            rent,
+            space as u64,
+            &token_2022::ID,
+        ),
+        metadata_pointer::instruction::initialize(
+            &token_2022::ID,
+            certificate,
+            Some(authority),
+            Some(*certificate),
+        )
+        .unwrap(),
+        token_instruction::initialize_mint_close_authority(
+            &token_2022::ID,
+            certificate,
+            Some(&authority),
+        )
+        .unwrap(),
+        token_instruction::initialize_permanent_delegate(&token_2022::ID, certificate, &authority)
+            .unwrap(),
+        token_instruction::initialize_mint2(&token_2022::ID, certificate, forger, None, 0).unwrap(),
+        metadata_instruction::initialize(
+            &token_2022::ID,
+            certificate,
+            forger,
+            certificate,
+            forger,
+            RETIRED_CREDITS_CERT_NAME.to_string(),
+            RETIRED_CREDITS_CERT_SYMBOL.to_string(),
+            String::new(),
+        ),
+    ];
+    create.extend(fields.into_iter().map(|(key, value)| {
+        metadata_instruction::update_field(
+            &token_2022::ID,
+            certificate,
+            forger,
+            Field::Key(key.to_string()),
+            value,
+        )
+    }));
+
+    let holder_account = associated_token_address(forger, certificate);
+    let hand_over = vec![
+        metadata_instruction::update_authority(
+            &token_2022::ID,
+            certificate,
+            forger,
+            OptionalNonZeroPubkey(authority),
+        ),
+        anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account(
+            forger,
+            forger,
+            certificate,
+            &token_2022::ID,
+        ),
+        token_instruction::mint_to(&token_2022::ID, certificate, &holder_account, forger, &[], 1)
+            .unwrap(),
+        token_instruction::set_authority(
+            &token_2022::ID,
+            certificate,
+            None,
+            AuthorityType::MintTokens,
+            forger,
+            &[],
+        )
+        .unwrap(),
+    ];
+    [create, hand_over]
+}
+
+#[tokio::test]
+async fn retirement_certificate_verifies() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), 100).await;
+    let certificate = retire(&mut context, &rwa, RETIRED).await;
+
+    let verification = verify(&mut context, &certificate, &rwa.rwa_mint).await;
+    assert_eq!(failed(&verification), Vec::<&str>::new());
+    assert!(verification.is_genuine());
+    assert_eq!(verification.retired_credits, Some(RETIRED));
+    assert!(verification.retired_by.is_some());
+
+    // wallets follow the metadata pointer, which must lead back to the certificate
+    let data = account_data(&mut context, &certificate).await.unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(&data).unwrap();
+    let pointer = mint.get_extension::<MetadataPointer>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(pointer.metadata_address),
+        Some(certificate)
+    );
+
+    let verification = verify(&mut context, &certificate, &Pubkey::new_unique()).await;
+    assert_eq!(
+        failed(&verification),
+        ["address", "update_authority", "extension_authorities"]
+    );
+}
+
+#[tokio::test]
+async fn certificate_of_a_closed_mint_still_verifies() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, 100).await;
+    let certificate = retire(&mut context, &rwa, to_base_units(50)).await;
+    send(
+        &mut context,
+        &[instructions::close_rwa_token(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            true,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        account_data(&mut context, &mint_authority_pda(&rwa.rwa_mint)).await,
+        None
+    );
+
+    let verification = verify(&mut context, &certificate, &rwa.rwa_mint).await;
+    assert_eq!(failed(&verification), Vec::<&str>::new());
+    assert_eq!(verification.retired_credits, Some(to_base_units(50)));
+}
+
+#[tokio::test]
+async fn forged_certificate_is_rejected() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), 100).await;
+    let alice = Keypair::new();
+    let forger = Keypair::new();
+    let certificate = Keypair::new();
+    let rent = context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(1_000);
+    send(
+        &mut context,
+        &[system_instruction::transfer(
+            &rwa.admin.pubkey(),
+            &forger.pubkey(),
+            10 * rent,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let [create, hand_over] = forge_certificate_ixs(
+        &forger.pubkey(),
+        &certificate.pubkey(),
+        &rwa.rwa_mint,
+        &alice.pubkey(),
+        rent,
+    );
+    send(&mut context, &create, &[&forger, &certificate])
+        .await
+        .unwrap();
+    send(&mut context, &hand_over, &[&forger]).await.unwrap();
+
+    // every field retire_token writes is there, only the address gives the forgery away
+    let verification = verify(&mut context, &certificate.pubkey(), &rwa.rwa_mint).await;
+    assert_eq!(failed(&verification), ["address"]);
+    assert!(!verification.is_genuine());
+    assert_eq!(verification.retired_by, None);
+}
+
+#[tokio::test]
+async fn other_mints_are_not_retirement_certificates() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), 100).await;
+
+    // a minter certificate is issued by the program too, but under its own controller
+    let verification = verify(&mut context, &rwa.minter_nft_mint(), &rwa.rwa_mint).await;
+    assert!(!verification.is_genuine());
+    assert_eq!(
+        failed(&verification),
+        [
+            "metadata",
+            "retired_credits",
+            "address",
+            "update_authority",
+            "extension_authorities"
+        ]
+    );
+
+    let verification = verify(&mut context, &rwa.rwa_mint, &rwa.rwa_mint).await;
+    assert!(!verification.is_genuine());
+    assert!(failed(&verification).contains(&"supply"));
+
+    let verification = verify(&mut context, &Pubkey::new_unique(), &rwa.rwa_mint).await;
+    assert_eq!(failed(&verification), ["exists"]);
+}
@@ -0,0 +1,488 @@
+#![allow(dead_code)]
+
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize, Discriminator};
+use anchor_spl::{
+    associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent,
+    token_2022::{
+        self,
+        spl_token_2022::{
+            self,
+            extension::StateWithExtensions,
+            state::{Account as TokenAccount, Mint},
+        },
+    },
+};
+use rwa_tokenization::{error::MyErrorCode, instruction};
+use rwa_tokenization_client::{
+    credits, instructions, token_metadata,
+    transfer_hook::{self, HookTransferAccounts},
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::{Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use token_transfer_hook::{InvestorClass, Jurisdiction, CONSUMER_CONTROLLER_JURISDICTION_OFFSET};
+
+pub use rwa_tokenization_client::pda::*;
+
+// anchor entrypoints tie the account infos to one lifetime, program-test expects them unbound
+macro_rules! anchor_processor {
+    ($entry:path) => {
+        processor!(|program_id, accounts, data| {
+            let accounts = Box::leak(Box::new(accounts.to_vec()));
+            $entry(program_id, accounts, data)
+        })
+    };
+}
+
+pub const DECIMALS: u8 = 2;
+pub const TRANSFER_FEE_BASIS_POINTS: u16 = 100;
+pub const MAXIMUM_FEE: u64 = 1_000;
+
+// runs the programs natively, or the built `.so` files when SBF_OUT_DIR/BPF_OUT_DIR is set
+pub fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new(
+        "rwa_tokenization",
+        rwa_tokenization::ID,
+        anchor_processor!(rwa_tokenization::entry),
+    );
+    program_test.add_program(
+        "token_transfer_hook",
+        token_transfer_hook::ID,
+        anchor_processor!(token_transfer_hook::entry),
+    );
+    program_test
+}
+
+pub async fn send(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let transaction = build_transaction(context, instructions, signers).await;
+    context.banks_client.process_transaction(transaction).await
+}
+
+// simulates first to capture the inner instructions carrying the event cpi data, then commits
+pub async fn send_with_inner_data(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<Vec<Vec<u8>>, BanksClientError> {
+    let transaction = build_transaction(context, instructions, signers).await;
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction.clone())
+        .await?;
+    let inner_data = simulation
+        .simulation_details
+        .and_then(|details| details.inner_instructions)
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .map(|inner| inner.instruction.data)
+        .collect();
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await?;
+    Ok(inner_data)
+}
+
+// commits the transaction and returns the compute units it consumed
+pub async fn send_measured(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<u64, BanksClientError> {
+    let transaction = build_transaction(context, instructions, signers).await;
+    let outcome = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await?;
+    outcome.result.map_err(BanksClientError::TransactionError)?;
+    Ok(outcome
+        .metadata
+        .map(|metadata| metadata.compute_units_consumed)
+        .unwrap_or_default())
+}
+
+async fn build_transaction(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Transaction {
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    )
+}
+
+pub fn assert_custom_error(result: Result<(), BanksClientError>, code: u32) {
+    match result.expect_err("transaction should fail").unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(actual)) => {
+            assert_eq!(actual, code, "unexpected error code")
+        }
+        err => panic!("expected custom error {code}, got {err:?}"),
+    }
+}
+
+pub fn assert_rwa_error(result: Result<(), BanksClientError>, error: MyErrorCode) {
+    assert_custom_error(result, error.into());
+}
+
+// event cpi data: instruction tag, event discriminator, borsh encoded event
+pub fn find_event<T: AnchorDeserialize + Discriminator>(inner_data: &[Vec<u8>]) -> Option<T> {
+    let tag = anchor_lang::event::EVENT_IX_TAG_LE;
+    inner_data.iter().find_map(|data| {
+        let data = data.strip_prefix(tag)?;
+        let data = data.strip_prefix(T::DISCRIMINATOR)?;
+        T::deserialize(&mut &data[..]).ok()
+    })
+}
+
+pub async fn account_data(context: &mut ProgramTestContext, address: &Pubkey) -> Option<Vec<u8>> {
+    context
+        .banks_client
+        .get_account(*address)
+        .await
+        .unwrap()
+        .map(|account| account.data)
+}
+
+pub async fn token_amount(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let data = account_data(context, token_account).await.unwrap();
+    StateWithExtensions::<TokenAccount>::unpack(&data)
+        .unwrap()
+        .base
+        .amount
+}
+
+pub async fn is_frozen(context: &mut ProgramTestContext, token_account: &Pubkey) -> bool {
+    let data = account_data(context, token_account).await.unwrap();
+    StateWithExtensions::<TokenAccount>::unpack(&data)
+        .unwrap()
+        .base
+        .is_frozen()
+}
+
+pub async fn mint_supply(context: &mut ProgramTestContext, mint: &Pubkey) -> u64 {
+    let data = account_data(context, mint).await.unwrap();
+    StateWithExtensions::<Mint>::unpack(&data)
+        .unwrap()
+        .base
+        .supply
+}
+
+// credit counters kept in the token metadata of certificate nfts
+pub async fn nft_credits(context: &mut ProgramTestContext, nft_mint: &Pubkey, key: &str) -> u64 {
+    let data = account_data(context, nft_mint).await.unwrap();
+    credits(&token_metadata(&data).unwrap(), key).unwrap()
+}
+
+// cut the consumer's controller back to its layout from before jurisdictions were stored
+pub async fn make_legacy_controller(
+    context: &mut ProgramTestContext,
+    rwa: &RwaFixture,
+    consumer: &Pubkey,
+) {
+    let controller = consumer_controller_pda(&consumer_nft_pda(&rwa.rwa_mint, consumer));
+    let mut account = context
+        .banks_client
+        .get_account(controller)
+        .await
+        .unwrap()
+        .unwrap();
+    account
+        .data
+        .truncate(CONSUMER_CONTROLLER_JURISDICTION_OFFSET);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    account.lamports = rent.minimum_balance(account.data.len());
+    context.set_account(&controller, &AccountSharedData::from(account));
+}
+
+pub fn jurisdiction(country_code: &[u8; 2], investor_class: InvestorClass) -> Jurisdiction {
+    Jurisdiction {
+        country_code: *country_code,
+        investor_class,
+    }
+}
+
+pub fn to_base_units(credits: u64) -> u64 {
+    credits * 10u64.pow(DECIMALS as u32)
+}
+
+pub fn initialize_ix(authority: &Pubkey) -> Instruction {
+    instructions::initialize(authority)
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct RwaTokenOptions {
+    pub is_close: bool,
+    pub has_fee: bool,
+    pub default_frozen: bool,
+    pub has_clawback: bool,
+}
+
+pub fn init_rwa_token_ix(
+    authority: &Pubkey,
+    symbol: &str,
+    options: RwaTokenOptions,
+) -> Instruction {
+    instructions::init_rwa_token(
+        authority,
+        instruction::InitRwaToken {
+            name: "Carbon Credits".to_string(),
+            symbol: symbol.to_string(),
+            decimals: DECIMALS,
+            uri: "https://example.com/cct.json".to_string(),
+            is_close: options.is_close,
+            has_fee: options.has_fee,
+            transfer_fee_basis_points: options.has_fee.then_some(TRANSFER_FEE_BASIS_POINTS),
+            maximum_fee: options.has_fee.then_some(MAXIMUM_FEE),
+            default_frozen: options.default_frozen,
+            has_clawback: options.has_clawback,
+        },
+    )
+}
+
+pub fn issue_minter_cert_ix(authority: &Pubkey, rwa_mint: &Pubkey, minter: &Pubkey) -> Instruction {
+    instructions::issue_minter_cert(
+        authority,
+        rwa_mint,
+        minter,
+        instruction::IssueMinterCert {
+            name: "Minter NFT".to_string(),
+            symbol: "MNT".to_string(),
+            uri: "https://example.com/minter.json".to_string(),
+        },
+    )
+}
+
+pub fn update_quota_credit_ix(
+    authority: &Pubkey,
+    rwa_mint: &Pubkey,
+    minter: &Pubkey,
+    new_credit: u64,
+) -> Instruction {
+    instructions::update_quota_credit(
+        authority,
+        rwa_mint,
+        minter,
+        instruction::UpdateQuotaCredit { new_credit },
+    )
+}
+
+pub fn issue_consumer_cert_ix(
+    payer: &Pubkey,
+    minter: &Pubkey,
+    rwa_mint: &Pubkey,
+    consumer: &Pubkey,
+    jurisdiction: Jurisdiction,
+) -> Instruction {
+    instructions::issue_consumer_cert(
+        payer,
+        minter,
+        rwa_mint,
+        consumer,
+        instruction::IssueConsumerCert {
+            name: "Consumer NFT".to_string(),
+            symbol: "CST".to_string(),
+            uri: "https://example.com/consumer.json".to_string(),
+            jurisdiction,
+        },
+    )
+}
+
+pub fn mint_rwa_token_ix(
+    payer: &Pubkey,
+    minter: &Pubkey,
+    rwa_mint: &Pubkey,
+    receiver: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    instructions::mint_rwa_token(
+        payer,
+        minter,
+        rwa_mint,
+        receiver,
+        instruction::MintRwaToken {
+            amount,
+            lockup_until: None,
+            linear_vesting: false,
+        },
+    )
+}
+
+pub fn retire_token_ix(
+    payer: &Pubkey,
+    consumer: &Pubkey,
+    rwa_mint: &Pubkey,
+    retirement_id: u64,
+    amount: u64,
+) -> Instruction {
+    instructions::retire_token(
+        payer,
+        consumer,
+        rwa_mint,
+        instruction::RetireToken {
+            amount,
+            retirement_id,
+        },
+    )
+}
+
+/// An initialized config and RWA mint with a minter holding `credits` of quota.
+pub struct RwaFixture {
+    pub admin: Keypair,
+    pub minter: Keypair,
+    pub rwa_mint: Pubkey,
+    pub options: RwaTokenOptions,
+}
+
+impl RwaFixture {
+    pub async fn start(options: RwaTokenOptions, credits: u64) -> (ProgramTestContext, Self) {
+        let mut context = program_test().start_with_context().await;
+        let admin = context.payer.insecure_clone();
+        send(&mut context, &[initialize_ix(&admin.pubkey())], &[])
+            .await
+            .unwrap();
+        let fixture = Self::create_mint(&mut context, "CCT", options, credits).await;
+        (context, fixture)
+    }
+
+    // another RWA mint under the already initialized config
+    pub async fn create_mint(
+        context: &mut ProgramTestContext,
+        symbol: &str,
+        options: RwaTokenOptions,
+        credits: u64,
+    ) -> Self {
+        let admin = context.payer.insecure_clone();
+        let minter = Keypair::new();
+        let rwa_mint = rwa_mint_pda(symbol);
+        send(
+            context,
+            &[
+                init_rwa_token_ix(&admin.pubkey(), symbol, options),
+                issue_minter_cert_ix(&admin.pubkey(), &rwa_mint, &minter.pubkey()),
+                update_quota_credit_ix(&admin.pubkey(), &rwa_mint, &minter.pubkey(), credits),
+            ],
+            &[],
+        )
+        .await
+        .unwrap();
+        Self {
+            admin,
+            minter,
+            rwa_mint,
+            options,
+        }
+    }
+
+    pub async fn issue_consumer(&self, context: &mut ProgramTestContext, consumer: &Pubkey) {
+        send(
+            context,
+            &[issue_consumer_cert_ix(
+                &self.admin.pubkey(),
+                &self.minter.pubkey(),
+                &self.rwa_mint,
+                consumer,
+                jurisdiction(b"US", InvestorClass::Retail),
+            )],
+            &[&self.minter],
+        )
+        .await
+        .unwrap();
+    }
+
+    pub fn mint_ix(&self, receiver: &Pubkey, credits: u64) -> Instruction {
+        mint_rwa_token_ix(
+            &self.admin.pubkey(),
+            &self.minter.pubkey(),
+            &self.rwa_mint,
+            receiver,
+            credits,
+        )
+    }
+
+    pub async fn mint(&self, context: &mut ProgramTestContext, receiver: &Pubkey, credits: u64) {
+        send(context, &[self.mint_ix(receiver, credits)], &[&self.minter])
+            .await
+            .unwrap();
+    }
+
+    pub fn minter_nft_mint(&self) -> Pubkey {
+        minter_nft_pda(&self.rwa_mint, &self.minter.pubkey())
+    }
+
+    pub fn token_account(&self, owner: &Pubkey) -> Pubkey {
+        associated_token_address(owner, &self.rwa_mint)
+    }
+
+    pub fn create_token_account_ix(&self, owner: &Pubkey) -> Instruction {
+        create_associated_token_account_idempotent(
+            &self.admin.pubkey(),
+            owner,
+            &self.rwa_mint,
+            &token_2022::ID,
+        )
+    }
+
+    pub fn approve_ix(&self, owner: &Pubkey, delegate: &Pubkey, amount: u64) -> Instruction {
+        spl_token_2022::instruction::approve(
+            &token_2022::ID,
+            &self.token_account(owner),
+            delegate,
+            owner,
+            &[],
+            amount,
+        )
+        .unwrap()
+    }
+
+    // transfer between the owners' associated token accounts, through the hook when the mint has one
+    pub fn transfer_ix(&self, from: &Pubkey, to: &Pubkey, amount: u64) -> Instruction {
+        self.delegated_transfer_ix(from, from, to, amount)
+    }
+
+    // transfer out of `from`'s account signed by `authority`, its owner or a delegate
+    pub fn delegated_transfer_ix(
+        &self,
+        authority: &Pubkey,
+        from: &Pubkey,
+        to: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let accounts = HookTransferAccounts {
+            source: self.token_account(from),
+            mint: self.rwa_mint,
+            destination: self.token_account(to),
+            authority: *authority,
+            source_owner: *from,
+            destination_owner: *to,
+        };
+        if self.options.is_close {
+            return transfer_hook::transfer_checked(&accounts, amount, DECIMALS);
+        }
+        spl_token_2022::instruction::transfer_checked(
+            &token_2022::ID,
+            &accounts.source,
+            &accounts.mint,
+            &accounts.destination,
+            &accounts.authority,
+            &[],
+            amount,
+            DECIMALS,
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use common::*;
+use rwa_tokenization::{error::MyErrorCode, instruction, ComplianceActionKind};
+use rwa_tokenization_client::{
+    compliance_action,
+    instructions::{self, TokenAccountRef},
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+// base units, not whole credits
+const CLAWED_BACK: u64 = 1_234;
+
+#[tokio::test]
+async fn hold_freezes_the_account_until_released() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    let alice_account = rwa.token_account(&alice.pubkey());
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&bob.pubkey()),
+            instructions::freeze_account(&rwa.admin.pubkey(), &rwa.rwa_mint, &alice_account),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(is_frozen(&mut context, &alice_account).await);
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), 1)],
+        &[&alice],
+    )
+    .await;
+    assert!(result.is_err());
+
+    send(
+        &mut context,
+        &[instructions::release_hold(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &alice_account,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(!is_frozen(&mut context, &alice_account).await);
+    assert_eq!(
+        account_data(&mut context, &account_hold_pda(&alice_account)).await,
+        None
+    );
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), 1)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn release_hold_keeps_default_frozen_account_frozen() {
+    let options = RwaTokenOptions {
+        default_frozen: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    // never issued a consumer cert, so the account must stay frozen
+    let mallory = Keypair::new();
+    let mallory_account = rwa.token_account(&mallory.pubkey());
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&mallory.pubkey()),
+            instructions::freeze_account(&rwa.admin.pubkey(), &rwa.rwa_mint, &mallory_account),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    send(
+        &mut context,
+        &[instructions::release_hold(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &mallory_account,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(is_frozen(&mut context, &mallory_account).await);
+    assert_eq!(
+        account_data(&mut context, &account_hold_pda(&mallory_account)).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn release_hold_thaws_account_frozen_by_the_hold_on_default_frozen_mint() {
+    let options = RwaTokenOptions {
+        default_frozen: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    let alice_account = rwa.token_account(&alice.pubkey());
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&alice.pubkey()),
+            instructions::thaw_for_consumer(&alice.pubkey(), &rwa.rwa_mint),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    send(
+        &mut context,
+        &[instructions::freeze_account(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &alice_account,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let result = send(
+        &mut context,
+        &[instructions::thaw_for_consumer(
+            &alice.pubkey(),
+            &rwa.rwa_mint,
+        )],
+        &[&alice],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::AccountOnHold);
+
+    send(
+        &mut context,
+        &[instructions::release_hold(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &alice_account,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(!is_frozen(&mut context, &alice_account).await);
+}
+
+#[tokio::test]
+async fn force_transfer_moves_held_balance_through_the_hook() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        has_clawback: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    let alice_account = rwa.token_account(&alice.pubkey());
+    let bob_account = rwa.token_account(&bob.pubkey());
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&bob.pubkey()),
+            instructions::freeze_account(&rwa.admin.pubkey(), &rwa.rwa_mint, &alice_account),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    send(
+        &mut context,
+        &[instructions::force_transfer(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &TokenAccountRef {
+                address: alice_account,
+                owner: alice.pubkey(),
+            },
+            &TokenAccountRef {
+                address: bob_account,
+                owner: bob.pubkey(),
+            },
+            true,
+            instruction::ForceTransfer {
+                action_id: 1,
+                amount: CLAWED_BACK,
+                reason: "court order".to_string(),
+                document_hash: [7; 32],
+            },
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(token_amount(&mut context, &bob_account).await, CLAWED_BACK);
+    assert_eq!(
+        token_amount(&mut context, &alice_account).await,
+        to_base_units(MINTED) - CLAWED_BACK
+    );
+    // the held account is frozen again after the move
+    assert!(is_frozen(&mut context, &alice_account).await);
+
+    let data = account_data(&mut context, &compliance_action_pda(&rwa.rwa_mint, 1))
+        .await
+        .unwrap();
+    let action = compliance_action(&data).unwrap();
+    assert!(action.kind == ComplianceActionKind::ForceTransfer);
+    assert_eq!(action.source, alice_account);
+    assert_eq!(action.destination, Some(bob_account));
+    assert_eq!(action.amount, CLAWED_BACK);
+}
+
+#[tokio::test]
+async fn force_burn_burns_base_units() {
+    let options = RwaTokenOptions {
+        has_fee: true,
+        has_clawback: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    let alice_account = rwa.token_account(&alice.pubkey());
+
+    send(
+        &mut context,
+        &[instructions::force_burn(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &alice_account,
+            instruction::ForceBurn {
+                action_id: 1,
+                amount: CLAWED_BACK,
+                reason: "fraud".to_string(),
+                document_hash: [9; 32],
+            },
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let remaining = to_base_units(MINTED) - CLAWED_BACK;
+    assert_eq!(token_amount(&mut context, &alice_account).await, remaining);
+    assert_eq!(mint_supply(&mut context, &rwa.rwa_mint).await, remaining);
+
+    let data = account_data(&mut context, &compliance_action_pda(&rwa.rwa_mint, 1))
+        .await
+        .unwrap();
+    let action = compliance_action(&data).unwrap();
+    assert!(action.kind == ComplianceActionKind::ForceBurn);
+    assert_eq!(action.destination, None);
+    assert_eq!(action.amount, CLAWED_BACK);
+}
@@ -0,0 +1,580 @@
+// Compute units of every instruction across extension combinations, checked against the
+// baseline in `tests/compute_units/sbf.txt` when the built `.so` files are tested. Run with
+// UPDATE_CU_BASELINE=1 to rewrite it.
+
+mod common;
+
+use std::{collections::BTreeMap, env, fs, path::PathBuf};
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use rwa_tokenization::{instruction, MetadataField};
+use rwa_tokenization_client::instructions::{self, TokenAccountRef};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    signature::{keypair_from_seed, Keypair, Signer},
+    system_instruction,
+};
+use token_transfer_hook::{DenyReason, InvestorClass, JurisdictionRule, TransferPolicyParams};
+
+// allowed growth over the baseline, in percent
+const TOLERANCE_PERCENT: u64 = 2;
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+const TRANSFERRED: u64 = 100;
+const RETIRED: u64 = 5_000;
+const CLAWED_BACK: u64 = 1_000;
+
+// every combination has clawback so the force instructions can be measured
+const COMBINATIONS: [(&str, RwaTokenOptions); 5] = [
+    (
+        "base",
+        RwaTokenOptions {
+            is_close: false,
+            has_fee: false,
+            default_frozen: false,
+            has_clawback: true,
+        },
+    ),
+    (
+        "fee",
+        RwaTokenOptions {
+            is_close: false,
+            has_fee: true,
+            default_frozen: false,
+            has_clawback: true,
+        },
+    ),
+    (
+        "hook",
+        RwaTokenOptions {
+            is_close: true,
+            has_fee: false,
+            default_frozen: false,
+            has_clawback: true,
+        },
+    ),
+    (
+        "hook_fee",
+        RwaTokenOptions {
+            is_close: true,
+            has_fee: true,
+            default_frozen: false,
+            has_clawback: true,
+        },
+    ),
+    (
+        "frozen",
+        RwaTokenOptions {
+            is_close: false,
+            has_fee: false,
+            default_frozen: true,
+            has_clawback: true,
+        },
+    ),
+];
+
+// fixed keys: PDA bump searches, and with them the measured units, depend on the addresses
+fn keypair(seed: u8) -> Keypair {
+    keypair_from_seed(&[seed; 32]).unwrap()
+}
+
+struct Bench {
+    context: ProgramTestContext,
+    units: Vec<(&'static str, u64)>,
+}
+
+impl Bench {
+    // one instruction per transaction so the units are its own
+    async fn measure(&mut self, name: &'static str, ix: Instruction, signers: &[&Keypair]) {
+        let units = send_measured(&mut self.context, &[ix], signers)
+            .await
+            .unwrap_or_else(|err| panic!("{name} failed: {err:?}"));
+        self.units.push((name, units));
+    }
+
+    // committed like the measured transactions: one sent through `send` right before can
+    // still hold its account locks and fail the next one with AccountInUse
+    async fn setup(&mut self, instructions: &[Instruction], signers: &[&Keypair]) {
+        send_measured(&mut self.context, instructions, signers)
+            .await
+            .unwrap();
+    }
+}
+
+async fn run_instructions(options: RwaTokenOptions) -> Vec<(&'static str, u64)> {
+    let context = program_test().start_with_context().await;
+    let admin = context.payer.insecure_clone();
+    let mut bench = Bench {
+        context,
+        units: Vec::new(),
+    };
+    let has_hook = options.is_close;
+    let minter = keypair(1);
+    let alice = keypair(2);
+    let bob = keypair(3);
+    let carol = keypair(4);
+    let new_minter = keypair(5);
+    let new_carol = keypair(6);
+    let officer = keypair(7);
+    let rwa = RwaFixture {
+        admin: admin.insecure_clone(),
+        minter: minter.insecure_clone(),
+        rwa_mint: rwa_mint_pda("CCT"),
+        options,
+    };
+    let mint = rwa.rwa_mint;
+
+    bench
+        .measure("initialize", initialize_ix(&admin.pubkey()), &[])
+        .await;
+    bench
+        .measure(
+            "init_rwa_token",
+            init_rwa_token_ix(&admin.pubkey(), "CCT", options),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "issue_minter_cert",
+            issue_minter_cert_ix(&admin.pubkey(), &mint, &minter.pubkey()),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "update_quota_credit",
+            update_quota_credit_ix(&admin.pubkey(), &mint, &minter.pubkey(), QUOTA),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "issue_consumer_cert",
+            issue_consumer_cert_ix(
+                &admin.pubkey(),
+                &minter.pubkey(),
+                &mint,
+                &alice.pubkey(),
+                jurisdiction(b"US", InvestorClass::Retail),
+            ),
+            &[&minter],
+        )
+        .await;
+    for consumer in [&bob, &carol] {
+        bench
+            .setup(
+                &[issue_consumer_cert_ix(
+                    &admin.pubkey(),
+                    &minter.pubkey(),
+                    &mint,
+                    &consumer.pubkey(),
+                    jurisdiction(b"US", InvestorClass::Retail),
+                )],
+                &[&minter],
+            )
+            .await;
+    }
+    bench
+        .measure(
+            "set_consumer_jurisdiction",
+            instructions::set_consumer_jurisdiction(
+                &admin.pubkey(),
+                &mint,
+                &carol.pubkey(),
+                instruction::SetConsumerJurisdiction {
+                    jurisdiction: jurisdiction(b"DE", InvestorClass::Professional),
+                },
+            ),
+            &[],
+        )
+        .await;
+
+    bench
+        .measure(
+            "mint_rwa_token",
+            rwa.mint_ix(&alice.pubkey(), MINTED),
+            &[&minter],
+        )
+        .await;
+    bench
+        .setup(&[rwa.create_token_account_ix(&bob.pubkey())], &[])
+        .await;
+    if options.default_frozen {
+        bench
+            .measure(
+                "thaw_for_consumer",
+                instructions::thaw_for_consumer(&alice.pubkey(), &mint),
+                &[&alice],
+            )
+            .await;
+        bench
+            .setup(
+                &[instructions::thaw_for_consumer(&bob.pubkey(), &mint)],
+                &[&bob],
+            )
+            .await;
+    }
+
+    bench
+        .measure(
+            "transfer_checked",
+            rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), to_base_units(TRANSFERRED)),
+            &[&alice],
+        )
+        .await;
+    bench
+        .measure(
+            "retire_token",
+            retire_token_ix(&admin.pubkey(), &bob.pubkey(), &mint, 0, RETIRED),
+            &[&bob],
+        )
+        .await;
+
+    let alice_account = rwa.token_account(&alice.pubkey());
+    let bob_account = rwa.token_account(&bob.pubkey());
+    bench
+        .measure(
+            "freeze_account",
+            instructions::freeze_account(&admin.pubkey(), &mint, &alice_account),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "release_hold",
+            instructions::release_hold(&admin.pubkey(), &mint, &alice_account),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "force_transfer",
+            instructions::force_transfer(
+                &admin.pubkey(),
+                &mint,
+                &TokenAccountRef {
+                    address: alice_account,
+                    owner: alice.pubkey(),
+                },
+                &TokenAccountRef {
+                    address: bob_account,
+                    owner: bob.pubkey(),
+                },
+                has_hook,
+                instruction::ForceTransfer {
+                    action_id: 1,
+                    amount: CLAWED_BACK,
+                    reason: "court order".to_string(),
+                    document_hash: [7; 32],
+                },
+            ),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "force_burn",
+            instructions::force_burn(
+                &admin.pubkey(),
+                &mint,
+                &bob_account,
+                instruction::ForceBurn {
+                    action_id: 2,
+                    amount: CLAWED_BACK,
+                    reason: "court order".to_string(),
+                    document_hash: [7; 32],
+                },
+            ),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "update_rwa_metadata",
+            instructions::update_rwa_metadata(
+                &admin.pubkey(),
+                &mint,
+                instruction::UpdateRwaMetadata {
+                    name: None,
+                    uri: Some("https://example.com/cct-v2.json".to_string()),
+                    fields: vec![MetadataField {
+                        key: "registry".to_string(),
+                        value: "verra".to_string(),
+                    }],
+                    remove_keys: vec![],
+                },
+            ),
+            &[],
+        )
+        .await;
+
+    bench
+        .measure(
+            "grant_compliance_role",
+            instructions::grant_compliance_role(&admin.pubkey(), &officer.pubkey()),
+            &[],
+        )
+        .await;
+    bench
+        .setup(
+            &[system_instruction::transfer(
+                &admin.pubkey(),
+                &officer.pubkey(),
+                LAMPORTS_PER_SOL,
+            )],
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "add_to_denylist",
+            instructions::add_to_denylist(
+                &officer.pubkey(),
+                &carol.pubkey(),
+                instruction::AddToDenylist {
+                    reason: DenyReason::Sanctions,
+                },
+            ),
+            &[&officer],
+        )
+        .await;
+    bench
+        .measure(
+            "remove_from_denylist",
+            instructions::remove_from_denylist(&officer.pubkey(), &carol.pubkey()),
+            &[&officer],
+        )
+        .await;
+    bench
+        .measure(
+            "revoke_compliance_role",
+            instructions::revoke_compliance_role(&admin.pubkey(), &officer.pubkey()),
+            &[],
+        )
+        .await;
+
+    if has_hook {
+        measure_hook_configuration(&mut bench, &admin, &mint, &bob_account).await;
+    }
+
+    bench
+        .measure(
+            "migrate_minter_holder",
+            instructions::migrate_minter_holder(
+                &admin.pubkey(),
+                &mint,
+                &minter.pubkey(),
+                &new_minter.pubkey(),
+                false,
+                has_hook,
+                false,
+            ),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "migrate_consumer_holder",
+            instructions::migrate_consumer_holder(
+                &admin.pubkey(),
+                &mint,
+                &carol.pubkey(),
+                &new_carol.pubkey(),
+                false,
+                has_hook,
+                false,
+            ),
+            &[],
+        )
+        .await;
+
+    // a second mint without supply to close
+    bench
+        .setup(&[init_rwa_token_ix(&admin.pubkey(), "CLS", options)], &[])
+        .await;
+    bench
+        .measure(
+            "close_rwa_token",
+            instructions::close_rwa_token(&admin.pubkey(), &rwa_mint_pda("CLS"), has_hook),
+            &[],
+        )
+        .await;
+
+    bench.units
+}
+
+async fn measure_hook_configuration(
+    bench: &mut Bench,
+    admin: &Keypair,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+) {
+    let params = TransferPolicyParams {
+        require_source_cert: true,
+        require_destination_cert: true,
+        min_amount: None,
+        max_amount: None,
+        trading_window: None,
+        enforce_lockups: false,
+        max_balance: None,
+        velocity_limit: None,
+        travel_rule_threshold: None,
+    };
+    bench
+        .measure(
+            "set_transfer_policy",
+            instructions::set_transfer_policy(
+                &admin.pubkey(),
+                mint,
+                instruction::SetTransferPolicy { params },
+            ),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "set_allowed_account",
+            instructions::set_allowed_account(
+                &admin.pubkey(),
+                mint,
+                token_account,
+                instruction::SetAllowedAccount { is_allowed: true },
+            ),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "set_allowed_program_owner",
+            instructions::set_allowed_program_owner(
+                &admin.pubkey(),
+                mint,
+                instruction::SetAllowedProgramOwner {
+                    program_id: keypair(9).pubkey(),
+                    is_allowed: true,
+                },
+            ),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "set_jurisdiction_rules",
+            instructions::set_jurisdiction_rules(
+                &admin.pubkey(),
+                mint,
+                instruction::SetJurisdictionRules {
+                    default_allowed: true,
+                    rules: vec![JurisdictionRule {
+                        jurisdiction: jurisdiction(b"US", InvestorClass::Retail),
+                        can_send: true,
+                        can_receive: true,
+                    }],
+                },
+            ),
+            &[],
+        )
+        .await;
+    bench
+        .measure(
+            "update_extra_account_meta_list",
+            instructions::update_extra_account_meta_list(&admin.pubkey(), mint),
+            &[],
+        )
+        .await;
+}
+
+// only the built `.so` files are metered as deployed: a native run meters just the BPF programs
+// it calls into (Token-2022, ATA, memo), so its units are not checked
+fn is_sbf_run() -> bool {
+    env::var_os("SBF_OUT_DIR").is_some() || env::var_os("BPF_OUT_DIR").is_some()
+}
+
+fn baseline_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/compute_units/sbf.txt")
+}
+
+// `<combination> <instruction> <units>` per line
+fn parse_baseline(contents: &str) -> BTreeMap<(String, String), u64> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [combination, name, units] = fields[..] else {
+                panic!("malformed baseline line: {line}");
+            };
+            let units = units
+                .parse()
+                .unwrap_or_else(|_| panic!("malformed baseline line: {line}"));
+            ((combination.to_string(), name.to_string()), units)
+        })
+        .collect()
+}
+
+fn format_baseline(measured: &[(&str, &str, u64)]) -> String {
+    let mut contents = String::from(
+        "# compute units per instruction, rewrite with UPDATE_CU_BASELINE=1 SBF_OUT_DIR=$PWD/target/deploy cargo test -p rwa-tokenization --test compute_units\n",
+    );
+    for (combination, name, units) in measured {
+        contents.push_str(&format!("{combination} {name} {units}\n"));
+    }
+    contents
+}
+
+#[tokio::test]
+async fn compute_units_stay_within_baseline() {
+    let update = env::var_os("UPDATE_CU_BASELINE").is_some();
+    assert!(
+        !update || is_sbf_run(),
+        "the compute unit baseline is recorded from the .so files, set SBF_OUT_DIR"
+    );
+    let mut measured = Vec::new();
+    for (combination, options) in COMBINATIONS {
+        for (name, units) in run_instructions(options).await {
+            measured.push((combination, name, units));
+        }
+    }
+
+    if !is_sbf_run() {
+        println!("native run, compute units not checked: set SBF_OUT_DIR to compare them");
+        return;
+    }
+    let path = baseline_path();
+    if update {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, format_baseline(&measured)).unwrap();
+        return;
+    }
+    let contents = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no compute unit baseline at {}, record one with UPDATE_CU_BASELINE=1",
+            path.display()
+        )
+    });
+    let baseline = parse_baseline(&contents);
+
+    let mut regressions = Vec::new();
+    for (combination, name, units) in &measured {
+        let key = (combination.to_string(), name.to_string());
+        match baseline.get(&key) {
+            Some(&expected) if units * 100 > expected * (100 + TOLERANCE_PERCENT) => regressions
+                .push(format!(
+                    "{combination} {name}: {units} CU, baseline {expected}"
+                )),
+            Some(_) => {}
+            None => regressions.push(format!("{combination} {name}: not in the baseline")),
+        }
+    }
+    assert!(
+        regressions.is_empty(),
+        "compute units above {} by more than {TOLERANCE_PERCENT}%:\n{}",
+        path.display(),
+        regressions.join("\n")
+    );
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use rwa_decoder::{decode_account, DecodeError, DecodedAccount, SCHEMA_VERSION};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::signature::{Keypair, Signer};
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+const RETIRED: u64 = 5_000;
+
+async fn decode(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+) -> Result<DecodedAccount, DecodeError> {
+    let account = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .unwrap()
+        .unwrap();
+    decode_account(&account.owner.to_string(), &account.data)
+}
+
+#[tokio::test]
+async fn decodes_mints_certificates_and_program_accounts() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        has_fee: true,
+        has_clawback: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    let certificate = retirement_certificate_pda(&rwa.rwa_mint, &alice.pubkey(), 0);
+    send(
+        &mut context,
+        &[retire_token_ix(
+            &rwa.admin.pubkey(),
+            &alice.pubkey(),
+            &rwa.rwa_mint,
+            0,
+            RETIRED,
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    let mint_authority = mint_authority_pda(&rwa.rwa_mint).to_string();
+
+    let mint = decode(&mut context, &rwa.rwa_mint).await.unwrap();
+    assert_eq!(mint.schema_version, SCHEMA_VERSION);
+    assert_eq!(mint.program, "token_2022");
+    assert_eq!(mint.kind, "rwa_mint");
+    assert_eq!(mint.data["decimals"], DECIMALS);
+    assert_eq!(
+        mint.data["supply"],
+        (to_base_units(MINTED) - RETIRED).to_string()
+    );
+    assert_eq!(mint.data["metadata"]["symbol"], "CCT");
+    let extensions = &mint.data["extensions"];
+    assert_eq!(
+        extensions["transfer_hook_program"],
+        token_transfer_hook::ID.to_string()
+    );
+    assert_eq!(
+        extensions["transfer_fee"]["basis_points"],
+        TRANSFER_FEE_BASIS_POINTS
+    );
+    assert_eq!(
+        extensions["transfer_fee"]["maximum_fee"],
+        MAXIMUM_FEE.to_string()
+    );
+    assert_eq!(extensions["permanent_delegate"], mint_authority);
+
+    let minter_cert = decode(&mut context, &rwa.minter_nft_mint()).await.unwrap();
+    assert_eq!(minter_cert.kind, "minter_certificate");
+    assert_eq!(
+        minter_cert.data["credits"]["available_credits"],
+        (QUOTA - MINTED).to_string()
+    );
+    assert_eq!(
+        minter_cert.data["credits"]["minted_credits"],
+        MINTED.to_string()
+    );
+
+    let consumer_nft = consumer_nft_pda(&rwa.rwa_mint, &alice.pubkey());
+    let consumer_cert = decode(&mut context, &consumer_nft).await.unwrap();
+    assert_eq!(consumer_cert.kind, "consumer_certificate");
+
+    let retirement = decode(&mut context, &certificate).await.unwrap();
+    assert_eq!(retirement.kind, "retirement_certificate");
+    assert_eq!(
+        retirement.data["credits"]["retired_credits"],
+        RETIRED.to_string()
+    );
+    assert_eq!(
+        retirement.data["metadata"]["update_authority"],
+        mint_authority
+    );
+    assert_eq!(
+        retirement.data["extensions"]["metadata_pointer"]["metadata_address"],
+        certificate.to_string()
+    );
+
+    let authority = decode(&mut context, &mint_authority_pda(&rwa.rwa_mint))
+        .await
+        .unwrap();
+    assert_eq!(authority.program, "rwa_tokenization");
+    assert_eq!(authority.kind, "mint_authority");
+    assert_eq!(authority.data["mint"], rwa.rwa_mint.to_string());
+    assert_eq!(
+        authority.data["transfer_hook"],
+        token_transfer_hook::ID.to_string()
+    );
+
+    let controller = consumer_controller_pda(&consumer_nft);
+    let controller = decode(&mut context, &controller).await.unwrap();
+    assert_eq!(controller.kind, "consumer_controller");
+    assert_eq!(controller.data["user"], alice.pubkey().to_string());
+    assert_eq!(controller.data["jurisdiction"]["investor_class"], "retail");
+
+    let token_account = rwa.token_account(&alice.pubkey());
+    assert!(matches!(
+        decode(&mut context, &token_account).await,
+        Err(DecodeError::NotAnRwaMint(_))
+    ));
+}
@@ -0,0 +1,454 @@
+// One test per reachable `MyErrorCode`. Not covered: `NoCredits` and `InvalidCredit` need
+// minter credits that do not parse, which no instruction can write.
+
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::clock::Clock};
+use anchor_spl::token_2022::{self, spl_token_2022};
+use common::*;
+use rwa_tokenization::{error::MyErrorCode, instruction, MAX_COMPLIANCE_REASON_LEN};
+use rwa_tokenization_client::instructions;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use token_transfer_hook::{InvestorClass, TransferPolicyParams};
+
+const QUOTA: u64 = 1_000;
+
+fn force_burn_ix(rwa: &RwaFixture, owner: &Pubkey, amount: u64, reason: String) -> Instruction {
+    instructions::force_burn(
+        &rwa.admin.pubkey(),
+        &rwa.rwa_mint,
+        &rwa.token_account(owner),
+        instruction::ForceBurn {
+            action_id: 1,
+            amount,
+            reason,
+            document_hash: [7; 32],
+        },
+    )
+}
+
+fn mint_with_lockup_ix(
+    rwa: &RwaFixture,
+    receiver: &Pubkey,
+    lockup_until: i64,
+    linear_vesting: bool,
+) -> Instruction {
+    instructions::mint_rwa_token(
+        &rwa.admin.pubkey(),
+        &rwa.minter.pubkey(),
+        &rwa.rwa_mint,
+        receiver,
+        instruction::MintRwaToken {
+            amount: 1,
+            lockup_until: Some(lockup_until),
+            linear_vesting,
+        },
+    )
+}
+
+// a hook mint whose policy enforces lockups
+async fn start_with_lockups() -> (ProgramTestContext, RwaFixture) {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let params = TransferPolicyParams {
+        require_source_cert: false,
+        require_destination_cert: false,
+        min_amount: None,
+        max_amount: None,
+        trading_window: None,
+        enforce_lockups: true,
+        max_balance: None,
+        velocity_limit: None,
+        travel_rule_threshold: None,
+    };
+    send(
+        &mut context,
+        &[instructions::set_transfer_policy(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            instruction::SetTransferPolicy { params },
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    (context, rwa)
+}
+
+async fn now(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+#[tokio::test]
+async fn mint_above_quota_fails_with_insufficient_credits() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    let result = send(
+        &mut context,
+        &[rwa.mint_ix(&alice.pubkey(), QUOTA + 1)],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InsufficientCredits);
+}
+
+#[tokio::test]
+async fn mint_overflowing_base_units_fails_with_overflow() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), u64::MAX).await;
+    let alice = Keypair::new();
+    let result = send(
+        &mut context,
+        &[rwa.mint_ix(&alice.pubkey(), u64::MAX)],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::Overflow);
+}
+
+#[tokio::test]
+async fn zero_amounts_fail_with_invalid_amount() {
+    let options = RwaTokenOptions {
+        has_clawback: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+
+    let result = send(
+        &mut context,
+        &[rwa.mint_ix(&alice.pubkey(), 0)],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InvalidAmount);
+
+    rwa.mint(&mut context, &alice.pubkey(), 10).await;
+    let result = send(
+        &mut context,
+        &[retire_token_ix(
+            &rwa.admin.pubkey(),
+            &alice.pubkey(),
+            &rwa.rwa_mint,
+            0,
+            0,
+        )],
+        &[&alice],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InvalidAmount);
+
+    let result = send(
+        &mut context,
+        &[force_burn_ix(
+            &rwa,
+            &alice.pubkey(),
+            0,
+            "court order".into(),
+        )],
+        &[],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InvalidAmount);
+}
+
+#[tokio::test]
+async fn retire_above_balance_fails_with_insufficient_balance() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    rwa.mint(&mut context, &alice.pubkey(), 10).await;
+
+    let result = send(
+        &mut context,
+        &[retire_token_ix(
+            &rwa.admin.pubkey(),
+            &alice.pubkey(),
+            &rwa.rwa_mint,
+            0,
+            to_base_units(10) + 1,
+        )],
+        &[&alice],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InsufficientBalance);
+}
+
+#[tokio::test]
+async fn lowercase_country_fails_with_invalid_jurisdiction() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    let result = send(
+        &mut context,
+        &[issue_consumer_cert_ix(
+            &rwa.admin.pubkey(),
+            &rwa.minter.pubkey(),
+            &rwa.rwa_mint,
+            &alice.pubkey(),
+            jurisdiction(b"us", InvestorClass::Retail),
+        )],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InvalidJurisdiction);
+}
+
+#[tokio::test]
+async fn lockup_in_the_past_fails_with_invalid_lockup() {
+    let (mut context, rwa) = start_with_lockups().await;
+    let alice = Keypair::new();
+    let lockup_until = now(&mut context).await - 1;
+    let result = send(
+        &mut context,
+        &[mint_with_lockup_ix(
+            &rwa,
+            &alice.pubkey(),
+            lockup_until,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InvalidLockup);
+}
+
+#[tokio::test]
+async fn linear_lockup_over_cliff_locked_tokens_fails_with_lockup_schedule_mismatch() {
+    let (mut context, rwa) = start_with_lockups().await;
+    let alice = Keypair::new();
+    let lockup_until = now(&mut context).await + 3_600;
+    send(
+        &mut context,
+        &[mint_with_lockup_ix(
+            &rwa,
+            &alice.pubkey(),
+            lockup_until,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[mint_with_lockup_ix(
+            &rwa,
+            &alice.pubkey(),
+            lockup_until + 3_600,
+            true,
+        )],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::LockupScheduleMismatch);
+}
+
+#[tokio::test]
+async fn lockup_without_its_account_fails_with_missing_lockup_account() {
+    let (mut context, rwa) = start_with_lockups().await;
+    let alice = Keypair::new();
+    let lockup_until = now(&mut context).await + 3_600;
+    let mut ix = mint_with_lockup_ix(&rwa, &alice.pubkey(), lockup_until, false);
+    // anchor reads an optional account passed as the program id as absent
+    let lockup = lockup_pda(&rwa.rwa_mint, &alice.pubkey());
+    let meta = ix
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == lockup)
+        .unwrap();
+    meta.pubkey = rwa_tokenization::ID;
+    meta.is_writable = false;
+
+    let result = send(&mut context, &[ix], &[&rwa.minter]).await;
+    assert_rwa_error(result, MyErrorCode::MissingLockupAccount);
+}
+
+#[tokio::test]
+async fn lockup_without_enforcing_policy_fails_with_lockup_not_enforced() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    let lockup_until = now(&mut context).await + 3_600;
+    let result = send(
+        &mut context,
+        &[mint_with_lockup_ix(
+            &rwa,
+            &alice.pubkey(),
+            lockup_until,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::LockupNotEnforced);
+}
+
+#[tokio::test]
+async fn transfer_policy_without_hook_fails_with_missing_transfer_hook_accounts() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let params = TransferPolicyParams {
+        require_source_cert: true,
+        require_destination_cert: false,
+        min_amount: None,
+        max_amount: None,
+        trading_window: None,
+        enforce_lockups: false,
+        max_balance: None,
+        velocity_limit: None,
+        travel_rule_threshold: None,
+    };
+    let result = send(
+        &mut context,
+        &[instructions::set_transfer_policy(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            instruction::SetTransferPolicy { params },
+        )],
+        &[],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::MissingTransferHookAccounts);
+}
+
+#[tokio::test]
+async fn force_burn_without_clawback_fails_with_clawback_disabled() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    rwa.mint(&mut context, &alice.pubkey(), 10).await;
+    let result = send(
+        &mut context,
+        &[force_burn_ix(
+            &rwa,
+            &alice.pubkey(),
+            1,
+            "court order".into(),
+        )],
+        &[],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::ClawbackDisabled);
+}
+
+#[tokio::test]
+async fn long_reason_fails_with_reason_too_long() {
+    let options = RwaTokenOptions {
+        has_clawback: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    rwa.mint(&mut context, &alice.pubkey(), 10).await;
+    let reason = "x".repeat(MAX_COMPLIANCE_REASON_LEN + 1);
+    let result = send(
+        &mut context,
+        &[force_burn_ix(&rwa, &alice.pubkey(), 1, reason)],
+        &[],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::ReasonTooLong);
+}
+
+#[tokio::test]
+async fn close_with_supply_fails_with_supply_not_zero() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    rwa.mint(&mut context, &alice.pubkey(), 10).await;
+    let result = send(
+        &mut context,
+        &[instructions::close_rwa_token(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            false,
+        )],
+        &[],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::SupplyNotZero);
+}
+
+#[tokio::test]
+async fn thaw_of_held_account_fails_with_account_on_hold() {
+    let options = RwaTokenOptions {
+        default_frozen: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&alice.pubkey()),
+            instructions::freeze_account(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                &rwa.token_account(&alice.pubkey()),
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[instructions::thaw_for_consumer(
+            &alice.pubkey(),
+            &rwa.rwa_mint,
+        )],
+        &[&alice],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::AccountOnHold);
+}
+
+#[tokio::test]
+async fn thaw_after_burning_the_cert_fails_with_invalid_consumer_cert() {
+    let options = RwaTokenOptions {
+        default_frozen: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    let cert = consumer_nft_pda(&rwa.rwa_mint, &alice.pubkey());
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&alice.pubkey()),
+            spl_token_2022::instruction::burn(
+                &token_2022::ID,
+                &associated_token_address(&alice.pubkey(), &cert),
+                &cert,
+                &alice.pubkey(),
+                &[],
+                1,
+            )
+            .unwrap(),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[instructions::thaw_for_consumer(
+            &alice.pubkey(),
+            &rwa.rwa_mint,
+        )],
+        &[&alice],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::InvalidConsumerCert);
+}
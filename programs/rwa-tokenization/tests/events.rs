@@ -0,0 +1,184 @@
+mod common;
+
+use common::*;
+use rwa_tokenization::{
+    ConfigInitialized, ConsumerCertIssued, CreditsMinted, CreditsRetired, MinterCertIssued,
+    QuotaUpdated, RwaTokenCreated,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use token_transfer_hook::{InvestorClass, TransferDecision, TransferEvent};
+
+#[tokio::test]
+async fn every_instruction_emits_its_event() {
+    let mut context = program_test().start_with_context().await;
+    let admin = context.payer.insecure_clone();
+    let minter = Keypair::new();
+    let consumer = Keypair::new();
+    let symbol = "CCE";
+    let rwa_mint = rwa_mint_pda(symbol);
+
+    let inner = send_with_inner_data(&mut context, &[initialize_ix(&admin.pubkey())], &[])
+        .await
+        .unwrap();
+    let event = find_event::<ConfigInitialized>(&inner).unwrap();
+    assert_eq!(event.authority, admin.pubkey());
+
+    let options = RwaTokenOptions {
+        has_clawback: true,
+        ..Default::default()
+    };
+    let inner = send_with_inner_data(
+        &mut context,
+        &[init_rwa_token_ix(&admin.pubkey(), symbol, options)],
+        &[],
+    )
+    .await
+    .unwrap();
+    let event = find_event::<RwaTokenCreated>(&inner).unwrap();
+    assert_eq!(event.mint, rwa_mint);
+    assert_eq!(event.authority, admin.pubkey());
+    assert_eq!(event.symbol, symbol);
+    assert_eq!(event.decimals, DECIMALS);
+    assert!(!event.has_transfer_hook && !event.has_fee && !event.default_frozen);
+    assert!(event.has_clawback);
+
+    let inner = send_with_inner_data(
+        &mut context,
+        &[issue_minter_cert_ix(
+            &admin.pubkey(),
+            &rwa_mint,
+            &minter.pubkey(),
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let event = find_event::<MinterCertIssued>(&inner).unwrap();
+    assert_eq!(event.rwa_mint, rwa_mint);
+    assert_eq!(event.minter, minter.pubkey());
+    assert_eq!(event.nft_mint, minter_nft_pda(&rwa_mint, &minter.pubkey()));
+
+    let inner = send_with_inner_data(
+        &mut context,
+        &[update_quota_credit_ix(
+            &admin.pubkey(),
+            &rwa_mint,
+            &minter.pubkey(),
+            1_000,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let event = find_event::<QuotaUpdated>(&inner).unwrap();
+    assert_eq!(event.rwa_mint, rwa_mint);
+    assert_eq!(event.minter, minter.pubkey());
+    assert_eq!(event.available_credits, 1_000);
+
+    let consumer_jurisdiction = jurisdiction(b"SG", InvestorClass::Professional);
+    let inner = send_with_inner_data(
+        &mut context,
+        &[issue_consumer_cert_ix(
+            &admin.pubkey(),
+            &minter.pubkey(),
+            &rwa_mint,
+            &consumer.pubkey(),
+            consumer_jurisdiction,
+        )],
+        &[&minter],
+    )
+    .await
+    .unwrap();
+    let event = find_event::<ConsumerCertIssued>(&inner).unwrap();
+    assert_eq!(event.rwa_mint, rwa_mint);
+    assert_eq!(event.consumer, consumer.pubkey());
+    assert_eq!(
+        event.nft_mint,
+        consumer_nft_pda(&rwa_mint, &consumer.pubkey())
+    );
+    assert_eq!(event.issued_by, minter.pubkey());
+    assert_eq!(event.jurisdiction, consumer_jurisdiction);
+
+    let inner = send_with_inner_data(
+        &mut context,
+        &[mint_rwa_token_ix(
+            &admin.pubkey(),
+            &minter.pubkey(),
+            &rwa_mint,
+            &consumer.pubkey(),
+            300,
+        )],
+        &[&minter],
+    )
+    .await
+    .unwrap();
+    let event = find_event::<CreditsMinted>(&inner).unwrap();
+    assert_eq!(event.rwa_mint, rwa_mint);
+    assert_eq!(event.minter, minter.pubkey());
+    assert_eq!(event.receiver, consumer.pubkey());
+    assert_eq!(event.credits, 300);
+    assert_eq!(event.amount, 300 * 10u64.pow(DECIMALS as u32));
+    assert_eq!(event.lockup_until, None);
+
+    let certificate = retirement_certificate_pda(&rwa_mint, &consumer.pubkey(), 0);
+    let inner = send_with_inner_data(
+        &mut context,
+        &[retire_token_ix(
+            &admin.pubkey(),
+            &consumer.pubkey(),
+            &rwa_mint,
+            0,
+            50,
+        )],
+        &[&consumer],
+    )
+    .await
+    .unwrap();
+    let event = find_event::<CreditsRetired>(&inner).unwrap();
+    assert_eq!(event.rwa_mint, rwa_mint);
+    assert_eq!(event.consumer, consumer.pubkey());
+    assert_eq!(event.amount, 50);
+    assert_eq!(event.certificate_mint, certificate);
+}
+
+#[tokio::test]
+async fn delegated_transfer_event_names_the_source_owner() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, 1_000).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let delegate = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), 300).await;
+    let amount = to_base_units(100);
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&bob.pubkey()),
+            rwa.approve_ix(&alice.pubkey(), &delegate.pubkey(), amount),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+
+    let inner = send_with_inner_data(
+        &mut context,
+        &[rwa.delegated_transfer_ix(&delegate.pubkey(), &alice.pubkey(), &bob.pubkey(), amount)],
+        &[&delegate],
+    )
+    .await
+    .unwrap();
+    let event = find_event::<TransferEvent>(&inner).unwrap();
+    assert_eq!(event.mint, rwa.rwa_mint);
+    assert_eq!(event.source_token, rwa.token_account(&alice.pubkey()));
+    assert_eq!(event.source_owner, alice.pubkey());
+    assert_eq!(event.destination_token, rwa.token_account(&bob.pubkey()));
+    assert_eq!(event.destination_owner, bob.pubkey());
+    assert_eq!(event.amount, amount);
+    assert_eq!(event.decision, TransferDecision::PolicyPassed);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use anchor_lang::prelude::Rent;
+use common::*;
+use rwa_tokenization_client::instructions;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    signature::{Keypair, Signer},
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use token_transfer_hook::InitializeExtraAccountMetaList;
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+// the list before the denylist entries were added: indices 5 to 10
+const OLD_EXTRA_ACCOUNTS: usize = 6;
+
+// rewrite the mint's list as an older hook release left it, sized and funded for fewer accounts
+async fn install_old_list(context: &mut ProgramTestContext, rwa: &RwaFixture) {
+    let metas = InitializeExtraAccountMetaList::extra_account_metas().unwrap();
+    let size = ExtraAccountMetaList::size_of(OLD_EXTRA_ACCOUNTS).unwrap();
+    let mut data = vec![0; size];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas[..OLD_EXTRA_ACCOUNTS])
+        .unwrap();
+    let rent: Rent = context.banks_client.get_rent().await.unwrap();
+    let account = Account {
+        lamports: rent.minimum_balance(size),
+        data,
+        owner: token_transfer_hook::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.set_account(
+        &extra_account_meta_list_pda(&rwa.rwa_mint),
+        &AccountSharedData::from(account),
+    );
+}
+
+#[tokio::test]
+async fn update_grows_an_older_list_and_transfers_resolve_again() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    send(
+        &mut context,
+        &[rwa.create_token_account_ix(&bob.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    install_old_list(&mut context, &rwa).await;
+
+    // the current hook cannot run with the accounts the old list resolves
+    let amount = to_base_units(1);
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::AccountNotEnoughKeys.into(),
+    );
+
+    send(
+        &mut context,
+        &[instructions::update_extra_account_meta_list(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let list = context
+        .banks_client
+        .get_account(extra_account_meta_list_pda(&rwa.rwa_mint))
+        .await
+        .unwrap()
+        .unwrap();
+    let metas = InitializeExtraAccountMetaList::extra_account_metas().unwrap();
+    let size = ExtraAccountMetaList::size_of(metas.len()).unwrap();
+    assert_eq!(list.data.len(), size);
+    // the admin paid the rent for the grown list
+    let rent: Rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(list.lamports, rent.minimum_balance(size));
+    let mut expected = vec![0; size];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut expected, &metas).unwrap();
+    assert_eq!(list.data, expected);
+
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use common::*;
+use rwa_tokenization::instruction;
+use rwa_tokenization_client::instructions;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use token_transfer_hook::{
+    error::TokenTransferHookError, InvestorClass, Jurisdiction, JurisdictionRule,
+};
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+
+fn rule(jurisdiction: Jurisdiction, can_send: bool, can_receive: bool) -> JurisdictionRule {
+    JurisdictionRule {
+        jurisdiction,
+        can_send,
+        can_receive,
+    }
+}
+
+fn set_rules_ix(
+    rwa: &RwaFixture,
+    default_allowed: bool,
+    rules: Vec<JurisdictionRule>,
+) -> Instruction {
+    instructions::set_jurisdiction_rules(
+        &rwa.admin.pubkey(),
+        &rwa.rwa_mint,
+        instruction::SetJurisdictionRules {
+            default_allowed,
+            rules,
+        },
+    )
+}
+
+// a hook mint with alice (US retail) holding MINTED credits and bob (DE professional) a token account
+async fn start_with_consumers() -> (ProgramTestContext, RwaFixture, Keypair, Keypair) {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    send(
+        &mut context,
+        &[
+            issue_consumer_cert_ix(
+                &rwa.admin.pubkey(),
+                &rwa.minter.pubkey(),
+                &rwa.rwa_mint,
+                &bob.pubkey(),
+                jurisdiction(b"DE", InvestorClass::Professional),
+            ),
+            rwa.create_token_account_ix(&bob.pubkey()),
+        ],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+    (context, rwa, alice, bob)
+}
+
+#[tokio::test]
+async fn source_jurisdiction_without_send_rights_is_rejected() {
+    let (mut context, rwa, alice, bob) = start_with_consumers().await;
+    send(
+        &mut context,
+        &[set_rules_ix(
+            &rwa,
+            true,
+            vec![rule(
+                jurisdiction(b"US", InvestorClass::Retail),
+                false,
+                true,
+            )],
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), to_base_units(1))],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(
+        result,
+        TokenTransferHookError::SourceJurisdictionNotAllowed.into(),
+    );
+}
+
+#[tokio::test]
+async fn destination_jurisdiction_without_receive_rights_is_rejected() {
+    let (mut context, rwa, alice, bob) = start_with_consumers().await;
+    let professional = jurisdiction(b"DE", InvestorClass::Professional);
+    send(
+        &mut context,
+        &[set_rules_ix(
+            &rwa,
+            true,
+            vec![rule(professional, true, false)],
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), to_base_units(1))],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(
+        result,
+        TokenTransferHookError::DestinationJurisdictionNotAllowed.into(),
+    );
+
+    // lifting the restriction lets the same transfer through
+    let amount = to_base_units(1);
+    send(
+        &mut context,
+        &[
+            set_rules_ix(&rwa, true, vec![rule(professional, true, true)]),
+            rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn legacy_consumer_controller_follows_default_allowed() {
+    let (mut context, rwa, alice, bob) = start_with_consumers().await;
+    make_legacy_controller(&mut context, &rwa, &alice.pubkey()).await;
+    // alice's own jurisdiction could send, but a legacy controller does not store it
+    let rules = vec![
+        rule(jurisdiction(b"US", InvestorClass::Retail), true, true),
+        rule(jurisdiction(b"DE", InvestorClass::Professional), true, true),
+    ];
+    send(
+        &mut context,
+        &[set_rules_ix(&rwa, false, rules.clone())],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), to_base_units(1))],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(
+        result,
+        TokenTransferHookError::SourceJurisdictionNotAllowed.into(),
+    );
+
+    let amount = to_base_units(1);
+    send(
+        &mut context,
+        &[
+            set_rules_ix(&rwa, true, rules),
+            rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+}
@@ -0,0 +1,333 @@
+mod common;
+
+use common::*;
+use rwa_tokenization::{
+    instruction, AVAILABLE_CREDITS_KEY, MINTED_CREDITS_KEY, RETIRED_CREDITS_KEY,
+};
+use rwa_tokenization_client::{
+    instructions::{self, TokenAccountRef},
+    RETIREMENT_CERT_AUTHORITY_OFFSET,
+};
+use solana_sdk::signature::{Keypair, Signer};
+use token_transfer_hook::{
+    error::TokenTransferHookError, DenyReason, TransferDecision, TransferEvent,
+};
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+const TRANSFERRED: u64 = 100;
+const RETIRED: u64 = 5_000;
+
+// init, create the mint, issue certs, set quota, mint, transfer and retire
+async fn run_lifecycle(is_close: bool, has_fee: bool) {
+    let options = RwaTokenOptions {
+        is_close,
+        has_fee,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    let alice_account = rwa.token_account(&alice.pubkey());
+    assert_eq!(
+        token_amount(&mut context, &alice_account).await,
+        to_base_units(MINTED)
+    );
+    assert_eq!(
+        mint_supply(&mut context, &rwa.rwa_mint).await,
+        to_base_units(MINTED)
+    );
+    let minter_nft = rwa.minter_nft_mint();
+    assert_eq!(
+        nft_credits(&mut context, &minter_nft, AVAILABLE_CREDITS_KEY).await,
+        QUOTA - MINTED
+    );
+    assert_eq!(
+        nft_credits(&mut context, &minter_nft, MINTED_CREDITS_KEY).await,
+        MINTED
+    );
+
+    let amount = to_base_units(TRANSFERRED);
+    let fee = if has_fee {
+        (amount * TRANSFER_FEE_BASIS_POINTS as u64 / 10_000).min(MAXIMUM_FEE)
+    } else {
+        0
+    };
+    let inner = send_with_inner_data(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&bob.pubkey()),
+            rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    let bob_account = rwa.token_account(&bob.pubkey());
+    assert_eq!(token_amount(&mut context, &bob_account).await, amount - fee);
+    assert_eq!(
+        token_amount(&mut context, &alice_account).await,
+        to_base_units(MINTED) - amount
+    );
+
+    let event = find_event::<TransferEvent>(&inner);
+    if is_close {
+        let event = event.expect("the hook emits a transfer event");
+        assert_eq!(event.source_owner, alice.pubkey());
+        assert_eq!(event.destination_owner, bob.pubkey());
+        assert_eq!(event.amount, amount);
+        assert_eq!(event.fee_withheld, fee);
+        assert_eq!(event.decision, TransferDecision::PolicyPassed);
+    } else {
+        assert!(event.is_none());
+    }
+
+    let certificate = retirement_certificate_pda(&rwa.rwa_mint, &bob.pubkey(), 0);
+    send(
+        &mut context,
+        &[retire_token_ix(
+            &rwa.admin.pubkey(),
+            &bob.pubkey(),
+            &rwa.rwa_mint,
+            0,
+            RETIRED,
+        )],
+        &[&bob],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &bob_account).await,
+        amount - fee - RETIRED
+    );
+    assert_eq!(
+        mint_supply(&mut context, &rwa.rwa_mint).await,
+        to_base_units(MINTED) - RETIRED
+    );
+    assert_eq!(mint_supply(&mut context, &certificate).await, 1);
+    // the report looks certificates up by their MintAuthority at this offset
+    let certificate_data = account_data(&mut context, &certificate).await.unwrap();
+    let offset = RETIREMENT_CERT_AUTHORITY_OFFSET;
+    assert_eq!(
+        certificate_data[offset..offset + 32],
+        mint_authority_pda(&rwa.rwa_mint).to_bytes()
+    );
+    let certificate_account = associated_token_address(&bob.pubkey(), &certificate);
+    assert_eq!(token_amount(&mut context, &certificate_account).await, 1);
+    assert_eq!(
+        nft_credits(&mut context, &certificate, RETIRED_CREDITS_KEY).await,
+        RETIRED
+    );
+}
+
+#[tokio::test]
+async fn lifecycle_without_extensions() {
+    run_lifecycle(false, false).await;
+}
+
+#[tokio::test]
+async fn lifecycle_with_transfer_fee() {
+    run_lifecycle(false, true).await;
+}
+
+#[tokio::test]
+async fn lifecycle_with_transfer_hook() {
+    run_lifecycle(true, false).await;
+}
+
+#[tokio::test]
+async fn lifecycle_with_transfer_hook_and_fee() {
+    run_lifecycle(true, true).await;
+}
+
+#[tokio::test]
+async fn hook_rejects_transfer_to_wallet_without_consumer_cert() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let outsider = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+
+    let result = send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&outsider.pubkey()),
+            rwa.transfer_ix(&alice.pubkey(), &outsider.pubkey(), 1),
+        ],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(
+        result,
+        TokenTransferHookError::DestinationNotConsumer.into(),
+    );
+}
+
+#[tokio::test]
+async fn hook_exempts_allowlisted_token_account() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let vault = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+
+    let vault_account = rwa.token_account(&vault.pubkey());
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&vault.pubkey()),
+            instructions::set_allowed_account(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                &vault_account,
+                instruction::SetAllowedAccount { is_allowed: true },
+            ),
+        ],
+        &[&rwa.admin],
+    )
+    .await
+    .unwrap();
+
+    let amount = to_base_units(TRANSFERRED);
+    let inner = send_with_inner_data(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &vault.pubkey(), amount)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(token_amount(&mut context, &vault_account).await, amount);
+    let event = find_event::<TransferEvent>(&inner).expect("the hook emits a transfer event");
+    assert_eq!(event.decision, TransferDecision::AllowlistExempt);
+}
+
+#[tokio::test]
+async fn hook_lets_permanent_delegate_claw_back() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        has_clawback: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    send(
+        &mut context,
+        &[rwa.create_token_account_ix(&bob.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let amount = to_base_units(TRANSFERRED);
+    let alice_account = rwa.token_account(&alice.pubkey());
+    let bob_account = rwa.token_account(&bob.pubkey());
+    let inner = send_with_inner_data(
+        &mut context,
+        &[instructions::force_transfer(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &TokenAccountRef {
+                address: alice_account,
+                owner: alice.pubkey(),
+            },
+            &TokenAccountRef {
+                address: bob_account,
+                owner: bob.pubkey(),
+            },
+            true,
+            instruction::ForceTransfer {
+                action_id: 1,
+                amount,
+                reason: "court order".to_string(),
+                document_hash: [7; 32],
+            },
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(token_amount(&mut context, &bob_account).await, amount);
+    let event = find_event::<TransferEvent>(&inner).expect("the hook emits a transfer event");
+    assert_eq!(event.source_owner, alice.pubkey());
+    assert_eq!(event.decision, TransferDecision::PermanentDelegate);
+}
+
+#[tokio::test]
+async fn hook_checks_the_source_owner_on_delegate_transfers() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    // the delegate holds no consumer cert, the source checks are about alice
+    let delegate = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    let amount = to_base_units(TRANSFERRED);
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&bob.pubkey()),
+            rwa.approve_ix(&alice.pubkey(), &delegate.pubkey(), 2 * amount),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+
+    send(
+        &mut context,
+        &[rwa.delegated_transfer_ix(&delegate.pubkey(), &alice.pubkey(), &bob.pubkey(), amount)],
+        &[&delegate],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+
+    // denylisting alice stops her delegate too
+    send(
+        &mut context,
+        &[
+            instructions::grant_compliance_role(&rwa.admin.pubkey(), &rwa.admin.pubkey()),
+            instructions::add_to_denylist(
+                &rwa.admin.pubkey(),
+                &alice.pubkey(),
+                instruction::AddToDenylist {
+                    reason: DenyReason::Fraud,
+                },
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    let result = send(
+        &mut context,
+        &[rwa.delegated_transfer_ix(&delegate.pubkey(), &alice.pubkey(), &bob.pubkey(), amount)],
+        &[&delegate],
+    )
+    .await;
+    assert_custom_error(result, TokenTransferHookError::DenylistedFraud.into());
+}
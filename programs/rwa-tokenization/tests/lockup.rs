@@ -0,0 +1,393 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::clock::Clock};
+use common::*;
+use rwa_tokenization::{error::MyErrorCode, instruction, Lockup};
+use rwa_tokenization_client::{decode_account, instructions};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use token_transfer_hook::{error::TokenTransferHookError, TransferPolicyParams};
+
+const QUOTA: u64 = 1_000;
+const LOCKED: u64 = 100;
+const HOUR: i64 = 3_600;
+
+fn mint_locked_ix(
+    rwa: &RwaFixture,
+    receiver: &Pubkey,
+    credits: u64,
+    lockup_until: i64,
+    linear_vesting: bool,
+) -> Instruction {
+    instructions::mint_rwa_token(
+        &rwa.admin.pubkey(),
+        &rwa.minter.pubkey(),
+        &rwa.rwa_mint,
+        receiver,
+        instruction::MintRwaToken {
+            amount: credits,
+            lockup_until: Some(lockup_until),
+            linear_vesting,
+        },
+    )
+}
+
+async fn clock(context: &mut ProgramTestContext) -> Clock {
+    context.banks_client.get_sysvar::<Clock>().await.unwrap()
+}
+
+async fn warp_to(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = clock(context).await;
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+async fn lockup(context: &mut ProgramTestContext, rwa: &RwaFixture, holder: &Pubkey) -> Lockup {
+    let data = account_data(context, &lockup_pda(&rwa.rwa_mint, holder))
+        .await
+        .unwrap();
+    decode_account(&data).unwrap()
+}
+
+// a hook mint enforcing lockups, with alice and bob holding consumer certs
+async fn start_with_lockups(
+    has_clawback: bool,
+) -> (ProgramTestContext, RwaFixture, Keypair, Keypair) {
+    let options = RwaTokenOptions {
+        is_close: true,
+        has_clawback,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    let params = TransferPolicyParams {
+        require_source_cert: true,
+        require_destination_cert: true,
+        min_amount: None,
+        max_amount: None,
+        trading_window: None,
+        enforce_lockups: true,
+        max_balance: None,
+        velocity_limit: None,
+        travel_rule_threshold: None,
+    };
+    send(
+        &mut context,
+        &[
+            instructions::set_transfer_policy(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                instruction::SetTransferPolicy { params },
+            ),
+            rwa.create_token_account_ix(&bob.pubkey()),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    (context, rwa, alice, bob)
+}
+
+#[tokio::test]
+async fn cliff_lockup_blocks_transfers_until_it_ends() {
+    let (mut context, rwa, alice, bob) = start_with_lockups(false).await;
+    let unlock_at = clock(&mut context).await.unix_timestamp + HOUR;
+    send(
+        &mut context,
+        &[mint_locked_ix(
+            &rwa,
+            &alice.pubkey(),
+            LOCKED,
+            unlock_at,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), 1)],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(result, TokenTransferHookError::TokensLocked.into());
+
+    warp_to(&mut context, unlock_at).await;
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), 1)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn same_schedule_issuances_carry_locked_tokens_to_the_later_unlock() {
+    let (mut context, rwa, alice, _) = start_with_lockups(false).await;
+    let now = clock(&mut context).await.unix_timestamp;
+    send(
+        &mut context,
+        &[
+            mint_locked_ix(&rwa, &alice.pubkey(), LOCKED, now + 2 * HOUR, false),
+            mint_locked_ix(&rwa, &alice.pubkey(), LOCKED, now + HOUR, false),
+        ],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+
+    let schedule = lockup(&mut context, &rwa, &alice.pubkey()).await.schedule;
+    assert_eq!(schedule.locked_amount, to_base_units(2 * LOCKED));
+    assert_eq!(schedule.unlock_at, now + 2 * HOUR);
+    assert!(!schedule.is_linear);
+}
+
+#[tokio::test]
+async fn linear_lockup_may_follow_a_cliff_lockup_that_has_ended() {
+    let (mut context, rwa, alice, _) = start_with_lockups(false).await;
+    let now = clock(&mut context).await.unix_timestamp;
+    send(
+        &mut context,
+        &[mint_locked_ix(
+            &rwa,
+            &alice.pubkey(),
+            LOCKED,
+            now + HOUR,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+
+    warp_to(&mut context, now + HOUR).await;
+    send(
+        &mut context,
+        &[mint_locked_ix(
+            &rwa,
+            &alice.pubkey(),
+            LOCKED,
+            now + 3 * HOUR,
+            true,
+        )],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+
+    let schedule = lockup(&mut context, &rwa, &alice.pubkey()).await.schedule;
+    assert_eq!(schedule.locked_amount, to_base_units(LOCKED));
+    assert_eq!(schedule.start_at, now + HOUR);
+    assert!(schedule.is_linear);
+}
+
+#[tokio::test]
+async fn cliff_lockup_also_blocks_the_holders_delegate() {
+    let (mut context, rwa, alice, bob) = start_with_lockups(false).await;
+    let delegate = Keypair::new();
+    let unlock_at = clock(&mut context).await.unix_timestamp + HOUR;
+    send(
+        &mut context,
+        &[
+            mint_locked_ix(&rwa, &alice.pubkey(), LOCKED, unlock_at, false),
+            rwa.approve_ix(&alice.pubkey(), &delegate.pubkey(), 1),
+        ],
+        &[&rwa.minter, &alice],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[rwa.delegated_transfer_ix(&delegate.pubkey(), &alice.pubkey(), &bob.pubkey(), 1)],
+        &[&delegate],
+    )
+    .await;
+    assert_custom_error(result, TokenTransferHookError::TokensLocked.into());
+}
+
+#[tokio::test]
+async fn lockup_needs_a_policy_enforcing_it() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let unlock_at = clock(&mut context).await.unix_timestamp + HOUR;
+
+    // the hook has no policy for the mint yet
+    let mut ix = mint_locked_ix(&rwa, &alice.pubkey(), LOCKED, unlock_at, false);
+    let policy = transfer_policy_pda(&rwa.rwa_mint);
+    let meta = ix
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == policy)
+        .unwrap();
+    meta.pubkey = rwa_tokenization::ID;
+    let result = send(&mut context, &[ix], &[&rwa.minter]).await;
+    assert_rwa_error(result, MyErrorCode::LockupNotEnforced);
+
+    let params = TransferPolicyParams {
+        require_source_cert: false,
+        require_destination_cert: false,
+        min_amount: None,
+        max_amount: None,
+        trading_window: None,
+        enforce_lockups: false,
+        max_balance: None,
+        velocity_limit: None,
+        travel_rule_threshold: None,
+    };
+    send(
+        &mut context,
+        &[instructions::set_transfer_policy(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            instruction::SetTransferPolicy { params },
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let result = send(
+        &mut context,
+        &[mint_locked_ix(
+            &rwa,
+            &alice.pubkey(),
+            LOCKED,
+            unlock_at,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::LockupNotEnforced);
+}
+
+#[tokio::test]
+async fn migrated_holder_keeps_the_locked_tokens_locked() {
+    let (mut context, rwa, alice, bob) = start_with_lockups(true).await;
+    let unlock_at = clock(&mut context).await.unix_timestamp + HOUR;
+    send(
+        &mut context,
+        &[mint_locked_ix(
+            &rwa,
+            &alice.pubkey(),
+            LOCKED,
+            unlock_at,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+
+    let new_alice = Keypair::new();
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&new_alice.pubkey()),
+            instructions::migrate_consumer_holder(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                &alice.pubkey(),
+                &new_alice.pubkey(),
+                true,
+                true,
+                true,
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    let schedule = lockup(&mut context, &rwa, &new_alice.pubkey())
+        .await
+        .schedule;
+    assert_eq!(schedule.locked_amount, to_base_units(LOCKED));
+    assert_eq!(schedule.unlock_at, unlock_at);
+    assert_eq!(
+        lockup(&mut context, &rwa, &alice.pubkey())
+            .await
+            .schedule
+            .locked_amount,
+        0
+    );
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&new_alice.pubkey(), &bob.pubkey(), 1)],
+        &[&new_alice],
+    )
+    .await;
+    assert_custom_error(result, TokenTransferHookError::TokensLocked.into());
+
+    warp_to(&mut context, unlock_at).await;
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&new_alice.pubkey(), &bob.pubkey(), 1)],
+        &[&new_alice],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn migration_without_the_new_lockup_fails_with_missing_lockup_account() {
+    let (mut context, rwa, alice, _) = start_with_lockups(true).await;
+    let unlock_at = clock(&mut context).await.unix_timestamp + HOUR;
+    send(
+        &mut context,
+        &[mint_locked_ix(
+            &rwa,
+            &alice.pubkey(),
+            LOCKED,
+            unlock_at,
+            false,
+        )],
+        &[&rwa.minter],
+    )
+    .await
+    .unwrap();
+
+    let new_alice = Keypair::new();
+    let mut ix = instructions::migrate_consumer_holder(
+        &rwa.admin.pubkey(),
+        &rwa.rwa_mint,
+        &alice.pubkey(),
+        &new_alice.pubkey(),
+        true,
+        true,
+        true,
+    );
+    // anchor reads an optional account passed as the program id as absent
+    let new_lockup = lockup_pda(&rwa.rwa_mint, &new_alice.pubkey());
+    let meta = ix
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == new_lockup)
+        .unwrap();
+    meta.pubkey = rwa_tokenization::ID;
+    meta.is_writable = false;
+    let result = send(
+        &mut context,
+        &[rwa.create_token_account_ix(&new_alice.pubkey()), ix],
+        &[],
+    )
+    .await;
+    assert_rwa_error(result, MyErrorCode::MissingLockupAccount);
+}
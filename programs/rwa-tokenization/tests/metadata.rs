@@ -0,0 +1,154 @@
+mod common;
+
+use anchor_lang::prelude::{Pubkey, Rent};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use common::*;
+use rwa_tokenization::{instruction, MetadataField};
+use rwa_tokenization_client::{instructions, token_metadata};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+fn update_metadata_ix(
+    authority: &Pubkey,
+    rwa: &RwaFixture,
+    name: Option<&str>,
+    uri: Option<&str>,
+    fields: &[(&str, &str)],
+    remove_keys: &[&str],
+) -> Instruction {
+    instructions::update_rwa_metadata(
+        authority,
+        &rwa.rwa_mint,
+        instruction::UpdateRwaMetadata {
+            name: name.map(str::to_string),
+            uri: uri.map(str::to_string),
+            fields: fields
+                .iter()
+                .map(|(key, value)| MetadataField {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+            remove_keys: remove_keys.iter().map(|key| key.to_string()).collect(),
+        },
+    )
+}
+
+async fn metadata(context: &mut ProgramTestContext, rwa: &RwaFixture) -> TokenMetadata {
+    let data = account_data(context, &rwa.rwa_mint).await.unwrap();
+    token_metadata(&data).unwrap()
+}
+
+fn field<'a>(metadata: &'a TokenMetadata, key: &str) -> Option<&'a str> {
+    metadata
+        .additional_metadata
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value.as_str())
+}
+
+#[tokio::test]
+async fn authority_updates_name_and_uri() {
+    let (mut context, rwa) = RwaFixture::start(Default::default(), 0).await;
+    let before = metadata(&mut context, &rwa).await;
+
+    send(
+        &mut context,
+        &[update_metadata_ix(
+            &rwa.admin.pubkey(),
+            &rwa,
+            Some("Renamed Carbon Credit"),
+            Some("https://example.com/renamed.json"),
+            &[],
+            &[],
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let after = metadata(&mut context, &rwa).await;
+    assert_eq!(after.name, "Renamed Carbon Credit");
+    assert_eq!(after.uri, "https://example.com/renamed.json");
+    assert_eq!(after.symbol, before.symbol);
+    assert_eq!(after.additional_metadata, before.additional_metadata);
+}
+
+#[tokio::test]
+async fn authority_adds_and_removes_fields() {
+    let (mut context, rwa) = RwaFixture::start(Default::default(), 0).await;
+    send(
+        &mut context,
+        &[update_metadata_ix(
+            &rwa.admin.pubkey(),
+            &rwa,
+            None,
+            None,
+            &[("registry", "verra"), ("vintage", "2024")],
+            &[],
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let added = metadata(&mut context, &rwa).await;
+    assert_eq!(field(&added, "registry"), Some("verra"));
+    assert_eq!(field(&added, "vintage"), Some("2024"));
+    // the mint grew in place, its rent was topped up for the new size
+    let account = context
+        .banks_client
+        .get_account(rwa.rwa_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent: Rent = context.banks_client.get_rent().await.unwrap();
+    assert!(account.lamports >= rent.minimum_balance(account.data.len()));
+
+    send(
+        &mut context,
+        &[update_metadata_ix(
+            &rwa.admin.pubkey(),
+            &rwa,
+            None,
+            None,
+            &[("vintage", "2025")],
+            &["registry"],
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let updated = metadata(&mut context, &rwa).await;
+    assert_eq!(field(&updated, "registry"), None);
+    assert_eq!(field(&updated, "vintage"), Some("2025"));
+}
+
+#[tokio::test]
+async fn other_signers_cannot_update_metadata() {
+    let (mut context, rwa) = RwaFixture::start(Default::default(), 0).await;
+    let stranger = Keypair::new();
+
+    let result = send(
+        &mut context,
+        &[update_metadata_ix(
+            &stranger.pubkey(),
+            &rwa,
+            Some("Hijacked"),
+            None,
+            &[("registry", "forged")],
+            &[],
+        )],
+        &[&stranger],
+    )
+    .await;
+    assert_custom_error(
+        result,
+        anchor_lang::error::ErrorCode::ConstraintHasOne.into(),
+    );
+    assert_ne!(metadata(&mut context, &rwa).await.name, "Hijacked");
+}
@@ -0,0 +1,273 @@
+mod common;
+
+use anchor_lang::{error::ErrorCode, prelude::Pubkey, Space};
+use common::*;
+use rwa_tokenization::{
+    instruction, ConsumerController, AVAILABLE_CREDITS_KEY, MINTED_CREDITS_KEY,
+};
+use rwa_tokenization_client::{consumer_controller, instructions};
+use solana_sdk::signature::{Keypair, Signer};
+use token_transfer_hook::InvestorClass;
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+
+fn set_consumer_jurisdiction_ix(
+    rwa: &RwaFixture,
+    consumer: &Pubkey,
+) -> solana_sdk::instruction::Instruction {
+    instructions::set_consumer_jurisdiction(
+        &rwa.admin.pubkey(),
+        &rwa.rwa_mint,
+        consumer,
+        instruction::SetConsumerJurisdiction {
+            jurisdiction: jurisdiction(b"DE", InvestorClass::Professional),
+        },
+    )
+}
+
+#[tokio::test]
+async fn migrate_minter_holder_carries_the_quota_to_the_new_wallet() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+
+    let new_minter = Keypair::new();
+    send(
+        &mut context,
+        &[instructions::migrate_minter_holder(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &rwa.minter.pubkey(),
+            &new_minter.pubkey(),
+            false,
+            false,
+            false,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let old_cert = rwa.minter_nft_mint();
+    assert_eq!(account_data(&mut context, &old_cert).await, None);
+    assert_eq!(
+        account_data(&mut context, &minter_controller_pda(&old_cert)).await,
+        None
+    );
+    let new_cert = minter_nft_pda(&rwa.rwa_mint, &new_minter.pubkey());
+    assert_eq!(
+        nft_credits(&mut context, &new_cert, AVAILABLE_CREDITS_KEY).await,
+        QUOTA - MINTED
+    );
+    assert_eq!(
+        nft_credits(&mut context, &new_cert, MINTED_CREDITS_KEY).await,
+        MINTED
+    );
+
+    // the recovered minter keeps minting from the remaining quota
+    send(
+        &mut context,
+        &[mint_rwa_token_ix(
+            &rwa.admin.pubkey(),
+            &new_minter.pubkey(),
+            &rwa.rwa_mint,
+            &alice.pubkey(),
+            QUOTA - MINTED,
+        )],
+        &[&new_minter],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&alice.pubkey())).await,
+        to_base_units(QUOTA)
+    );
+}
+
+#[tokio::test]
+async fn migrate_consumer_holder_moves_the_balance_through_the_hook() {
+    let options = RwaTokenOptions {
+        is_close: true,
+        has_clawback: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+
+    let new_alice = Keypair::new();
+    send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&new_alice.pubkey()),
+            rwa.create_token_account_ix(&bob.pubkey()),
+            instructions::migrate_consumer_holder(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                &alice.pubkey(),
+                &new_alice.pubkey(),
+                true,
+                true,
+                false,
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&alice.pubkey())).await,
+        0
+    );
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&new_alice.pubkey())).await,
+        to_base_units(MINTED)
+    );
+    let old_cert = consumer_nft_pda(&rwa.rwa_mint, &alice.pubkey());
+    assert_eq!(account_data(&mut context, &old_cert).await, None);
+    let new_cert = consumer_nft_pda(&rwa.rwa_mint, &new_alice.pubkey());
+    let data = account_data(&mut context, &consumer_controller_pda(&new_cert))
+        .await
+        .unwrap();
+    let controller = consumer_controller(&data).unwrap();
+    assert_eq!(controller.user, new_alice.pubkey());
+    assert_eq!(
+        controller.jurisdiction,
+        jurisdiction(b"US", InvestorClass::Retail)
+    );
+
+    // the new cert passes the hook's consumer checks
+    let amount = to_base_units(1);
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&new_alice.pubkey(), &bob.pubkey(), amount)],
+        &[&new_alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn legacy_consumer_controller_thaws_once_its_jurisdiction_is_set() {
+    let options = RwaTokenOptions {
+        default_frozen: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    make_legacy_controller(&mut context, &rwa, &alice.pubkey()).await;
+    let result = send(
+        &mut context,
+        &[
+            rwa.create_token_account_ix(&alice.pubkey()),
+            instructions::thaw_for_consumer(&alice.pubkey(), &rwa.rwa_mint),
+        ],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(result, ErrorCode::AccountDidNotDeserialize.into());
+
+    send(
+        &mut context,
+        &[
+            set_consumer_jurisdiction_ix(&rwa, &alice.pubkey()),
+            rwa.create_token_account_ix(&alice.pubkey()),
+            instructions::thaw_for_consumer(&alice.pubkey(), &rwa.rwa_mint),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert!(!is_frozen(&mut context, &rwa.token_account(&alice.pubkey())).await);
+    let cert = consumer_nft_pda(&rwa.rwa_mint, &alice.pubkey());
+    let data = account_data(&mut context, &consumer_controller_pda(&cert))
+        .await
+        .unwrap();
+    let controller = consumer_controller(&data).unwrap();
+    assert_eq!(controller.user, alice.pubkey());
+    assert_eq!(
+        controller.jurisdiction,
+        jurisdiction(b"DE", InvestorClass::Professional)
+    );
+}
+
+#[tokio::test]
+async fn legacy_consumer_holder_migrates_once_its_jurisdiction_is_set() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    make_legacy_controller(&mut context, &rwa, &alice.pubkey()).await;
+
+    let new_alice = Keypair::new();
+    send(
+        &mut context,
+        &[
+            set_consumer_jurisdiction_ix(&rwa, &alice.pubkey()),
+            instructions::migrate_consumer_holder(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                &alice.pubkey(),
+                &new_alice.pubkey(),
+                false,
+                false,
+                false,
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    let new_cert = consumer_nft_pda(&rwa.rwa_mint, &new_alice.pubkey());
+    let data = account_data(&mut context, &consumer_controller_pda(&new_cert))
+        .await
+        .unwrap();
+    assert_eq!(
+        consumer_controller(&data).unwrap().jurisdiction,
+        jurisdiction(b"DE", InvestorClass::Professional)
+    );
+}
+
+#[tokio::test]
+async fn set_consumer_jurisdiction_grows_a_legacy_controller() {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), QUOTA).await;
+    let alice = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    make_legacy_controller(&mut context, &rwa, &alice.pubkey()).await;
+
+    send(
+        &mut context,
+        &[set_consumer_jurisdiction_ix(&rwa, &alice.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    let cert = consumer_nft_pda(&rwa.rwa_mint, &alice.pubkey());
+    let account = context
+        .banks_client
+        .get_account(consumer_controller_pda(&cert))
+        .await
+        .unwrap()
+        .unwrap();
+    let space = 8 + ConsumerController::INIT_SPACE;
+    assert_eq!(account.data.len(), space);
+    // the admin topped up the rent for the grown account
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(account.lamports, rent.minimum_balance(space));
+    let controller = consumer_controller(&account.data).unwrap();
+    assert_eq!(controller.rwa_mint, rwa.rwa_mint);
+    assert_eq!(controller.mint, cert);
+    assert_eq!(controller.user, alice.pubkey());
+    assert_eq!(
+        controller.jurisdiction,
+        jurisdiction(b"DE", InvestorClass::Professional)
+    );
+}
@@ -0,0 +1,166 @@
+// Random sequences of quota updates, mints and retirements checked against a model of the
+// minter's credits: every failure must be the expected `MyErrorCode`, never a panic, and the
+// on-chain counters must match the model after every step.
+
+mod common;
+
+use common::*;
+use proptest::prelude::*;
+use rwa_tokenization::{error::MyErrorCode, AVAILABLE_CREDITS_KEY, MINTED_CREDITS_KEY};
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::signature::{Keypair, Signer};
+
+const INITIAL_QUOTA: u64 = 500;
+
+#[derive(Clone, Debug)]
+enum Op {
+    UpdateQuota(u64),
+    // credits, in whole tokens
+    Mint(u64),
+    // base units
+    Retire(u64),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        2 => (0..1_000u64).prop_map(Op::UpdateQuota),
+        1 => Just(Op::UpdateQuota(u64::MAX)),
+        4 => (0..400u64).prop_map(Op::Mint),
+        1 => Just(Op::Mint(u64::MAX)),
+        3 => (0..to_base_units(300)).prop_map(Op::Retire),
+    ]
+}
+
+#[derive(Debug)]
+struct Ledger {
+    available: u64,
+    granted: u128,
+    minted: u64,
+    retired: u64,
+}
+
+impl Ledger {
+    fn supply(&self) -> u64 {
+        to_base_units(self.minted) - self.retired
+    }
+
+    // applies `op` and returns the error the program must fail with, if any
+    fn apply(&mut self, op: &Op) -> Option<MyErrorCode> {
+        match *op {
+            Op::UpdateQuota(credits) => {
+                self.available = credits;
+                self.granted += credits as u128;
+                None
+            }
+            Op::Mint(0) | Op::Retire(0) => Some(MyErrorCode::InvalidAmount),
+            Op::Mint(credits) if credits > self.available => Some(MyErrorCode::InsufficientCredits),
+            Op::Mint(credits) if credits.checked_mul(to_base_units(1)).is_none() => {
+                Some(MyErrorCode::Overflow)
+            }
+            Op::Mint(credits) => {
+                self.available -= credits;
+                self.minted += credits;
+                None
+            }
+            Op::Retire(amount) if amount > self.supply() => Some(MyErrorCode::InsufficientBalance),
+            Op::Retire(amount) => {
+                self.retired += amount;
+                None
+            }
+        }
+    }
+}
+
+async fn execute(
+    context: &mut ProgramTestContext,
+    rwa: &RwaFixture,
+    holder: &Keypair,
+    id: u64,
+    op: &Op,
+) -> Result<(), BanksClientError> {
+    match *op {
+        Op::UpdateQuota(credits) => {
+            let ix = update_quota_credit_ix(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                &rwa.minter.pubkey(),
+                credits,
+            );
+            send(context, &[ix], &[]).await
+        }
+        Op::Mint(credits) => {
+            let ix = rwa.mint_ix(&holder.pubkey(), credits);
+            send(context, &[ix], &[&rwa.minter]).await
+        }
+        Op::Retire(amount) => {
+            let ix = retire_token_ix(
+                &rwa.admin.pubkey(),
+                &holder.pubkey(),
+                &rwa.rwa_mint,
+                id,
+                amount,
+            );
+            send(context, &[ix], &[holder]).await
+        }
+    }
+}
+
+async fn run(ops: Vec<Op>) {
+    let (mut context, rwa) = RwaFixture::start(RwaTokenOptions::default(), INITIAL_QUOTA).await;
+    let holder = Keypair::new();
+    rwa.issue_consumer(&mut context, &holder.pubkey()).await;
+    send(
+        &mut context,
+        &[rwa.create_token_account_ix(&holder.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    let mut ledger = Ledger {
+        available: INITIAL_QUOTA,
+        granted: INITIAL_QUOTA as u128,
+        minted: 0,
+        retired: 0,
+    };
+
+    let minter_nft = rwa.minter_nft_mint();
+    let holder_account = rwa.token_account(&holder.pubkey());
+    for (id, op) in ops.iter().enumerate() {
+        let result = execute(&mut context, &rwa, &holder, id as u64, op).await;
+        match ledger.apply(op) {
+            Some(error) => assert_rwa_error(result, error),
+            None => result.unwrap_or_else(|err| panic!("{op:?} failed: {err}")),
+        }
+
+        assert_eq!(
+            nft_credits(&mut context, &minter_nft, AVAILABLE_CREDITS_KEY).await,
+            ledger.available
+        );
+        assert_eq!(
+            nft_credits(&mut context, &minter_nft, MINTED_CREDITS_KEY).await,
+            ledger.minted
+        );
+        assert!(ledger.minted as u128 <= ledger.granted);
+        assert_eq!(
+            mint_supply(&mut context, &rwa.rwa_mint).await,
+            ledger.supply()
+        );
+        assert_eq!(
+            token_amount(&mut context, &holder_account).await,
+            ledger.supply()
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn quota_accounting_matches_the_model(ops in prop::collection::vec(op(), 1..12)) {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(run(ops));
+    }
+}
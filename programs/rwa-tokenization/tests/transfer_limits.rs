@@ -0,0 +1,197 @@
+mod common;
+
+use anchor_lang::solana_program::clock::Clock;
+use common::*;
+use rwa_tokenization::instruction;
+use rwa_tokenization_client::{decode_account, instructions, transfer_hook};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::signature::{Keypair, Signer};
+use token_transfer_hook::{
+    error::TokenTransferHookError, TransferPolicyParams, TransferStats, VelocityLimit,
+};
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+const TRANSFERRED: u64 = 100;
+const HOUR: i64 = 3_600;
+
+fn policy(max_balance: Option<u64>, velocity_limit: Option<VelocityLimit>) -> TransferPolicyParams {
+    TransferPolicyParams {
+        require_source_cert: true,
+        require_destination_cert: true,
+        min_amount: None,
+        max_amount: None,
+        trading_window: None,
+        enforce_lockups: false,
+        max_balance,
+        velocity_limit,
+        travel_rule_threshold: None,
+    }
+}
+
+// a hook mint with the given policy, alice holding MINTED credits and bob a token account
+async fn start_with_policy(
+    params: TransferPolicyParams,
+) -> (ProgramTestContext, RwaFixture, Keypair, Keypair) {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    send(
+        &mut context,
+        &[
+            instructions::set_transfer_policy(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                instruction::SetTransferPolicy { params },
+            ),
+            rwa.create_token_account_ix(&bob.pubkey()),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    (context, rwa, alice, bob)
+}
+
+async fn initialize_stats(context: &mut ProgramTestContext, rwa: &RwaFixture, owner: &Keypair) {
+    send(
+        context,
+        &[transfer_hook::initialize_transfer_stats(
+            &rwa.admin.pubkey(),
+            &rwa.rwa_mint,
+            &owner.pubkey(),
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+}
+
+async fn stats(
+    context: &mut ProgramTestContext,
+    rwa: &RwaFixture,
+    owner: &Keypair,
+) -> TransferStats {
+    let data = account_data(context, &transfer_stats_pda(&rwa.rwa_mint, &owner.pubkey()))
+        .await
+        .unwrap();
+    decode_account(&data).unwrap()
+}
+
+async fn warp_by(context: &mut ProgramTestContext, seconds: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += seconds;
+    context.set_sysvar(&clock);
+}
+
+#[tokio::test]
+async fn transfer_above_the_max_balance_is_rejected() {
+    let max_balance = to_base_units(TRANSFERRED + TRANSFERRED / 2);
+    let (mut context, rwa, alice, bob) = start_with_policy(policy(Some(max_balance), None)).await;
+    let amount = to_base_units(TRANSFERRED);
+
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(result, TokenTransferHookError::MaxBalanceExceeded.into());
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn initialize_transfer_stats_starts_an_empty_window() {
+    let (mut context, rwa, alice, _) = start_with_policy(policy(None, None)).await;
+    initialize_stats(&mut context, &rwa, &alice).await;
+
+    let stats = stats(&mut context, &rwa, &alice).await;
+    assert_eq!(stats.mint, rwa.rwa_mint);
+    assert_eq!(stats.owner, alice.pubkey());
+    assert_eq!(stats.window_start, 0);
+    assert_eq!(stats.window_volume, 0);
+    assert_eq!(stats.last_transfer_slot, 0);
+}
+
+#[tokio::test]
+async fn velocity_limit_without_transfer_stats_is_rejected() {
+    let limit = VelocityLimit {
+        window_seconds: HOUR,
+        max_volume: to_base_units(MINTED),
+    };
+    let (mut context, rwa, alice, bob) = start_with_policy(policy(None, Some(limit))).await;
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), to_base_units(TRANSFERRED))],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(
+        result,
+        TokenTransferHookError::TransferStatsNotInitialized.into(),
+    );
+}
+
+#[tokio::test]
+async fn velocity_limit_caps_the_volume_until_the_window_rolls_over() {
+    let limit = VelocityLimit {
+        window_seconds: HOUR,
+        max_volume: to_base_units(TRANSFERRED + TRANSFERRED / 2),
+    };
+    let (mut context, rwa, alice, bob) = start_with_policy(policy(None, Some(limit))).await;
+    initialize_stats(&mut context, &rwa, &alice).await;
+    let amount = to_base_units(TRANSFERRED);
+
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    let first_window = stats(&mut context, &rwa, &alice).await;
+    assert_eq!(first_window.window_volume, amount);
+
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(result, TokenTransferHookError::VelocityLimitExceeded.into());
+
+    warp_by(&mut context, HOUR).await;
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    let second_window = stats(&mut context, &rwa, &alice).await;
+    assert!(second_window.window_start >= first_window.window_start + HOUR);
+    assert_eq!(second_window.window_volume, amount);
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        2 * amount
+    );
+}
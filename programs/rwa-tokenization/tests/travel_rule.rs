@@ -0,0 +1,136 @@
+mod common;
+
+use common::*;
+use rwa_tokenization::instruction;
+use rwa_tokenization_client::{instructions, transfer_hook};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use token_transfer_hook::{
+    error::TokenTransferHookError, TransferPolicyParams, MEMO_PROGRAM_ID, TRAVEL_RULE_MEMO_PREFIX,
+};
+
+const QUOTA: u64 = 1_000;
+const MINTED: u64 = 300;
+const THRESHOLD: u64 = 100;
+
+// a hook mint with a travel-rule threshold, alice holding MINTED credits and bob a token account
+async fn start_with_threshold() -> (ProgramTestContext, RwaFixture, Keypair, Keypair) {
+    let options = RwaTokenOptions {
+        is_close: true,
+        ..Default::default()
+    };
+    let (mut context, rwa) = RwaFixture::start(options, QUOTA).await;
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    rwa.issue_consumer(&mut context, &alice.pubkey()).await;
+    rwa.issue_consumer(&mut context, &bob.pubkey()).await;
+    rwa.mint(&mut context, &alice.pubkey(), MINTED).await;
+    let params = TransferPolicyParams {
+        require_source_cert: true,
+        require_destination_cert: true,
+        min_amount: None,
+        max_amount: None,
+        trading_window: None,
+        enforce_lockups: false,
+        max_balance: None,
+        velocity_limit: None,
+        travel_rule_threshold: Some(to_base_units(THRESHOLD)),
+    };
+    send(
+        &mut context,
+        &[
+            instructions::set_transfer_policy(
+                &rwa.admin.pubkey(),
+                &rwa.rwa_mint,
+                instruction::SetTransferPolicy { params },
+            ),
+            rwa.create_token_account_ix(&bob.pubkey()),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    (context, rwa, alice, bob)
+}
+
+fn memo(hash: &str) -> Instruction {
+    let mut data = TRAVEL_RULE_MEMO_PREFIX.to_vec();
+    data.extend_from_slice(hash.as_bytes());
+    Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[tokio::test]
+async fn transfers_up_to_the_threshold_need_no_memo() {
+    let (mut context, rwa, alice, bob) = start_with_threshold().await;
+    let amount = to_base_units(THRESHOLD);
+    send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn transfer_above_the_threshold_without_memo_is_rejected() {
+    let (mut context, rwa, alice, bob) = start_with_threshold().await;
+    let result = send(
+        &mut context,
+        &[rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), to_base_units(THRESHOLD) + 1)],
+        &[&alice],
+    )
+    .await;
+    assert_custom_error(result, TokenTransferHookError::TravelRuleMemoMissing.into());
+}
+
+#[tokio::test]
+async fn transfer_above_the_threshold_with_a_malformed_hash_is_rejected() {
+    let (mut context, rwa, alice, bob) = start_with_threshold().await;
+    let amount = to_base_units(THRESHOLD) + 1;
+    let not_hex = "zz".repeat(32);
+    let too_short = "ab".repeat(31);
+    for hash in [not_hex.as_str(), too_short.as_str()] {
+        let result = send(
+            &mut context,
+            &[
+                memo(hash),
+                rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount),
+            ],
+            &[&alice],
+        )
+        .await;
+        assert_custom_error(result, TokenTransferHookError::TravelRuleMemoMissing.into());
+    }
+}
+
+#[tokio::test]
+async fn transfer_above_the_threshold_with_a_travel_rule_memo_passes() {
+    let (mut context, rwa, alice, bob) = start_with_threshold().await;
+    let amount = to_base_units(THRESHOLD) + 1;
+    send(
+        &mut context,
+        &[
+            transfer_hook::travel_rule_memo(&[0xab; 32]),
+            rwa.transfer_ix(&alice.pubkey(), &bob.pubkey(), amount),
+        ],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        token_amount(&mut context, &rwa.token_account(&bob.pubkey())).await,
+        amount
+    );
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+#[constant]
+pub const TRANSFER_POLICY_SEED: &[u8] = b"transfer-policy";
+#[constant]
+pub const TRANSFER_STATS_SEED: &[u8] = b"transfer-stats";
+
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+// travel-rule memo: this prefix followed by the hex sha256 of the originator/beneficiary data
+pub const TRAVEL_RULE_MEMO_PREFIX: &[u8] = b"travel-rule:";
+pub const TRAVEL_RULE_HASH_HEX_LEN: usize = 64;
+
+// seed of the anchor event cpi authority, resolved as an extra account of execute
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+pub const MAX_ALLOWED_ACCOUNTS: usize = 16;
+pub const MAX_ALLOWED_PROGRAM_OWNERS: usize = 16;
+pub const MAX_JURISDICTION_RULES: usize = 32;
+
+// the only program allowed to set up the hook, and the owner of the accounts resolved by it
+pub const RWA_PROGRAM_ID: Pubkey = pubkey!("5HMSSNbK5v5VCuReWUxG3mGksuWZmcsu9e9fMygTvyJw");
+
+// seeds of the rwa-tokenization accounts resolved by the hook
+pub const CONSUMER_NFT_SEED: &[u8] = b"c"; // consumer
+pub const MINT_AUTHORITY_SEED: &[u8] = b"ma"; // mint authority
+pub const DENYLIST_SEED: &[u8] = b"deny"; // denylisted wallet
+pub const LOCKUP_SEED: &[u8] = b"lockup"; // lockup of a (mint, holder)
+
+// ConsumerController: discriminator, rwa_mint, mint, user, bump, then the jurisdiction
+pub const CONSUMER_CONTROLLER_JURISDICTION_OFFSET: usize = 8 + 32 * 3 + 1;
+
+pub const SECONDS_PER_HOUR: i64 = 60 * 60;
+pub const HOURS_PER_DAY: i64 = 24;
+pub const SECONDS_PER_DAY: i64 = SECONDS_PER_HOUR * HOURS_PER_DAY;
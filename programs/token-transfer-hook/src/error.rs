@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TokenTransferHookError {
+    #[msg("You are not authorized to perform this action")]
+    UnAuthorized,
+    #[msg("Source owner does not hold a consumer certificate")]
+    SourceNotConsumer,
+    #[msg("Destination owner does not hold a consumer certificate")]
+    DestinationNotConsumer,
+    #[msg("Transfer amount is below the policy minimum")]
+    AmountBelowMinimum,
+    #[msg("Transfer amount is above the policy maximum")]
+    AmountAboveMaximum,
+    #[msg("Transfers are not allowed at this hour")]
+    OutsideAllowedHours,
+    #[msg("Invalid transfer policy")]
+    InvalidPolicy,
+    #[msg("Destination balance would exceed the policy maximum")]
+    MaxBalanceExceeded,
+    #[msg("Transfer volume limit for the current window exceeded")]
+    VelocityLimitExceeded,
+    #[msg("Transfer stats account of the source owner is not initialized")]
+    TransferStatsNotInitialized,
+    #[msg("Too many allowed program owners")]
+    TooManyProgramOwners,
+    #[msg("Program cannot be allowlisted")]
+    InvalidProgramOwner,
+    #[msg("Too many allowed accounts")]
+    TooManyAllowedAccounts,
+    #[msg("Wallet is denylisted: sanctions")]
+    DenylistedSanctions,
+    #[msg("Wallet is denylisted: fraud")]
+    DenylistedFraud,
+    #[msg("Wallet is denylisted: court order")]
+    DenylistedCourtOrder,
+    #[msg("Wallet is denylisted")]
+    DenylistedOther,
+    #[msg("Source jurisdiction is not allowed to send this token")]
+    SourceJurisdictionNotAllowed,
+    #[msg("Destination jurisdiction is not allowed to receive this token")]
+    DestinationJurisdictionNotAllowed,
+    #[msg("Too many jurisdiction rules")]
+    TooManyJurisdictionRules,
+    #[msg("Invalid jurisdiction")]
+    InvalidJurisdiction,
+    #[msg("Transfer exceeds the unlocked balance")]
+    TokensLocked,
+    #[msg("Transfer requires a preceding travel-rule memo")]
+    TravelRuleMemoMissing,
+    #[msg("Overflow")]
+    Overflow,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransferDecision {
+    // every check of the mint's policy passed
+    PolicyPassed,
+    // an allowlisted vault skipped the consumer cert check on at least one side
+    AllowlistExempt,
+    // clawback or holder migration by the mint's permanent delegate, checks skipped
+    PermanentDelegate,
+}
+
+#[event]
+pub struct TransferEvent {
+    pub mint: Pubkey,
+    pub source_token: Pubkey,
+    pub source_owner: Pubkey,
+    pub destination_token: Pubkey,
+    pub destination_owner: Pubkey,
+    pub amount: u64,
+    pub fee_withheld: u64,
+    pub slot: u64,
+    pub decision: TransferDecision,
+}
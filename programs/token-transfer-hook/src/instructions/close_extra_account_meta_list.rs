@@ -0,0 +1,37 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    check_transfer_hook_authority, error::TokenTransferHookError, EXTRA_ACCOUNT_METAS_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CloseExtraAccountMetaList<'info> {
+    /// the mint's transfer hook authority, usually a PDA signing via CPI
+    pub authority: Signer<'info>,
+    /// CHECK: receives the reclaimed rent
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    /// CHECK: ExtraAccountMetaList Account,
+    #[account(mut, seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+impl<'info> CloseExtraAccountMetaList<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        check_transfer_hook_authority(&self.mint.to_account_info(), &self.authority.key())?;
+
+        let extra_account_meta_list = self.extra_account_meta_list.to_account_info();
+        let destination = self.destination.to_account_info();
+        **destination.lamports.borrow_mut() = destination
+            .lamports()
+            .checked_add(extra_account_meta_list.lamports())
+            .ok_or(TokenTransferHookError::Overflow)?;
+        **extra_account_meta_list.lamports.borrow_mut() = 0;
+        extra_account_meta_list.assign(&system_program::ID);
+        extra_account_meta_list.realloc(0, false)?;
+
+        Ok(())
+    }
+}
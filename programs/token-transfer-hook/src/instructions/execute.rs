@@ -0,0 +1,364 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::{
+        self,
+        instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+
+use crate::{
+    check_token_account_is_transferring, error::TokenTransferHookError, get_transfer_fee,
+    is_consumer_cert, DenyReason, Jurisdiction, LockupSchedule, TransferDecision, TransferEvent,
+    TransferPolicy, TransferStats, CONSUMER_CONTROLLER_JURISDICTION_OFFSET, CONSUMER_NFT_SEED,
+    DENYLIST_SEED, EXTRA_ACCOUNT_METAS_SEED, HOURS_PER_DAY, LOCKUP_SEED, MEMO_PROGRAM_ID,
+    MINT_AUTHORITY_SEED, SECONDS_PER_HOUR, TRANSFER_POLICY_SEED, TRANSFER_STATS_SEED,
+    TRAVEL_RULE_HASH_HEX_LEN, TRAVEL_RULE_MEMO_PREFIX,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    // index 0-3 are the accounts required for token transfer (source, mint, destination, owner)
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: source token account owner, can be SystemAccount or PDA owned by another program
+    pub owner: UncheckedAccount<'info>,
+    // index 4 is address of ExtraAccountMetaList account
+    // The `addExtraAccountsToInstruction` JS helper function resolving incorrectly
+    /// CHECK: ExtraAccountMetaList Account,
+    #[account(seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    /// CHECK: Program id issue the certificate nft
+    pub rwa_program: AccountInfo<'info>,
+    /// CHECK: consumer cert nft of the source owner, address checked by the extra account metas
+    pub source_mint_nft: UncheckedAccount<'info>,
+    /// CHECK: consumer cert nft of the destination owner, address checked by the extra account metas
+    pub destination_mint_nft: UncheckedAccount<'info>,
+    /// CHECK: TransferPolicy of the mint, may not be initialized
+    #[account(seeds = [TRANSFER_POLICY_SEED, mint.key().as_ref()], bump)]
+    pub transfer_policy: UncheckedAccount<'info>,
+    /// CHECK: TransferStats of the source owner, may not be initialized
+    #[account(
+        mut,
+        seeds = [TRANSFER_STATS_SEED, mint.key().as_ref(), source_token.owner.as_ref()],
+        bump
+    )]
+    pub transfer_stats: UncheckedAccount<'info>,
+    /// CHECK: destination token account owner, can be SystemAccount or PDA owned by another program
+    #[account(address = destination_token.owner)]
+    pub destination_owner: UncheckedAccount<'info>,
+    /// CHECK: Denylist entry of the source owner in rwa-tokenization, may not be initialized
+    #[account(
+        seeds = [DENYLIST_SEED, source_token.owner.as_ref()],
+        seeds::program = rwa_program.key(),
+        bump
+    )]
+    pub source_denylist: UncheckedAccount<'info>,
+    /// CHECK: Denylist entry of the destination owner in rwa-tokenization, may not be initialized
+    #[account(
+        seeds = [DENYLIST_SEED, destination_token.owner.as_ref()],
+        seeds::program = rwa_program.key(),
+        bump
+    )]
+    pub destination_denylist: UncheckedAccount<'info>,
+    /// CHECK: ConsumerController of the source cert nft in rwa-tokenization, may not be initialized
+    #[account(
+        seeds = [CONSUMER_NFT_SEED, source_mint_nft.key().as_ref()],
+        seeds::program = rwa_program.key(),
+        bump
+    )]
+    pub source_consumer_controller: UncheckedAccount<'info>,
+    /// CHECK: ConsumerController of the destination cert nft in rwa-tokenization, may not be initialized
+    #[account(
+        seeds = [CONSUMER_NFT_SEED, destination_mint_nft.key().as_ref()],
+        seeds::program = rwa_program.key(),
+        bump
+    )]
+    pub destination_consumer_controller: UncheckedAccount<'info>,
+    /// CHECK: Lockup of the source owner in rwa-tokenization, may not be initialized
+    #[account(
+        seeds = [LOCKUP_SEED, mint.key().as_ref(), source_token.owner.as_ref()],
+        seeds::program = rwa_program.key(),
+        bump
+    )]
+    pub source_lockup: UncheckedAccount<'info>,
+    /// CHECK: instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> TransferHook<'info> {
+    pub fn handler(&mut self, amount: u64) -> Result<TransferEvent> {
+        check_token_account_is_transferring(
+            &self.source_token.to_account_info().try_borrow_data()?,
+        )?;
+        check_token_account_is_transferring(
+            &self.destination_token.to_account_info().try_borrow_data()?,
+        )?;
+
+        msg!("Transferring {} tokens", amount);
+
+        let data = self.extra_account_meta_list.try_borrow_data()?;
+        ExtraAccountMetaList::check_account_infos::<ExecuteInstruction>(
+            &self.to_account_infos(),
+            &TransferHookInstruction::Execute { amount }.pack(),
+            &crate::ID,
+            &data,
+        )?;
+
+        // clawback and holder migration move balances as the mint's permanent delegate. Any
+        // other transfer is signed by the source owner or a delegate (token-2022 checked the
+        // signer), so owner-scoped checks read the source token account's owner, not `owner`
+        if self.is_permanent_delegate_transfer() {
+            msg!("Transfer by permanent delegate, policy skipped");
+            return self.transfer_event(amount, TransferDecision::PermanentDelegate);
+        }
+
+        self.check_denylist(&self.source_denylist, "source")?;
+        self.check_denylist(&self.destination_denylist, "destination")?;
+
+        let policy = self.load_policy()?;
+        let decision = self.check_policy(&policy, amount)?;
+        self.check_jurisdictions(&policy)?;
+        self.record_transfer(&policy, amount)?;
+        self.transfer_event(amount, decision)
+    }
+
+    fn transfer_event(&self, amount: u64, decision: TransferDecision) -> Result<TransferEvent> {
+        Ok(TransferEvent {
+            mint: self.mint.key(),
+            source_token: self.source_token.key(),
+            source_owner: self.source_token.owner,
+            destination_token: self.destination_token.key(),
+            destination_owner: self.destination_token.owner,
+            amount,
+            fee_withheld: get_transfer_fee(&self.mint.to_account_info(), amount)?,
+            slot: Clock::get()?.slot,
+            decision,
+        })
+    }
+
+    fn is_permanent_delegate_transfer(&self) -> bool {
+        let (mint_authority, _) = Pubkey::find_program_address(
+            &[MINT_AUTHORITY_SEED, self.mint.key().as_ref()],
+            self.rwa_program.key,
+        );
+        self.owner.key() == mint_authority
+    }
+
+    fn check_denylist(&self, denylist: &UncheckedAccount<'info>, side: &str) -> Result<()> {
+        if denylist.data_is_empty() || denylist.owner != self.rwa_program.key {
+            return Ok(());
+        }
+        // Denylist stores the reason right after the account discriminator
+        let data = denylist.try_borrow_data()?;
+        let reason = DenyReason::deserialize(&mut &data[8..])?;
+        msg!("Denylisted {} owner: {:?}", side, reason);
+        Err(match reason {
+            DenyReason::Sanctions => TokenTransferHookError::DenylistedSanctions,
+            DenyReason::Fraud => TokenTransferHookError::DenylistedFraud,
+            DenyReason::CourtOrder => TokenTransferHookError::DenylistedCourtOrder,
+            DenyReason::Other => TokenTransferHookError::DenylistedOther,
+        }
+        .into())
+    }
+
+    // None for consumers issued before jurisdictions were stored
+    fn load_jurisdiction(
+        &self,
+        consumer_controller: &UncheckedAccount<'info>,
+    ) -> Result<Option<Jurisdiction>> {
+        let data = consumer_controller.try_borrow_data()?;
+        if data.len() <= CONSUMER_CONTROLLER_JURISDICTION_OFFSET {
+            return Ok(None);
+        }
+        Ok(Some(Jurisdiction::deserialize(
+            &mut &data[CONSUMER_CONTROLLER_JURISDICTION_OFFSET..],
+        )?))
+    }
+
+    fn check_jurisdictions(&self, policy: &TransferPolicy) -> Result<()> {
+        let Some(rules) = &policy.jurisdiction_rules else {
+            return Ok(());
+        };
+
+        // owners without a consumer cert (allowlisted vaults) are left to the cert checks
+        if self.source_consumer_controller.owner == self.rwa_program.key {
+            let source = self.load_jurisdiction(&self.source_consumer_controller)?;
+            require!(
+                rules.can_send(source),
+                TokenTransferHookError::SourceJurisdictionNotAllowed
+            );
+        }
+        if self.destination_consumer_controller.owner == self.rwa_program.key {
+            let destination = self.load_jurisdiction(&self.destination_consumer_controller)?;
+            require!(
+                rules.can_receive(destination),
+                TokenTransferHookError::DestinationJurisdictionNotAllowed
+            );
+        }
+        Ok(())
+    }
+
+    fn load_policy(&self) -> Result<TransferPolicy> {
+        if self.transfer_policy.data_is_empty() {
+            return Ok(TransferPolicy::permissioned(self.mint.key()));
+        }
+        require_keys_eq!(
+            *self.transfer_policy.owner,
+            crate::ID,
+            TokenTransferHookError::InvalidPolicy
+        );
+        let data = self.transfer_policy.try_borrow_data()?;
+        TransferPolicy::try_deserialize(&mut &data[..])
+    }
+
+    // approved vaults (escrow, AMM pools) can never hold a consumer cert. `owner_program` is the
+    // program owning the token account's owner, None when it is not known
+    fn is_allowlisted(
+        policy: &TransferPolicy,
+        token_account: &Pubkey,
+        owner_program: Option<&Pubkey>,
+    ) -> bool {
+        policy.allowed_accounts.contains(token_account)
+            || owner_program.is_some_and(|program| policy.allowed_program_owners.contains(program))
+    }
+
+    fn check_policy(&self, policy: &TransferPolicy, amount: u64) -> Result<TransferDecision> {
+        let mut decision = TransferDecision::PolicyPassed;
+        if policy.require_source_cert {
+            // a delegate signing for the source says nothing about the program owning the owner
+            let owner_program =
+                (self.owner.key() == self.source_token.owner).then_some(self.owner.owner);
+            if Self::is_allowlisted(policy, &self.source_token.key(), owner_program) {
+                decision = TransferDecision::AllowlistExempt;
+            } else {
+                require!(
+                    is_consumer_cert(&self.source_mint_nft)?,
+                    TokenTransferHookError::SourceNotConsumer
+                );
+            }
+        }
+        if policy.require_destination_cert {
+            if Self::is_allowlisted(
+                policy,
+                &self.destination_token.key(),
+                Some(self.destination_owner.owner),
+            ) {
+                decision = TransferDecision::AllowlistExempt;
+            } else {
+                require!(
+                    is_consumer_cert(&self.destination_mint_nft)?,
+                    TokenTransferHookError::DestinationNotConsumer
+                );
+            }
+        }
+        if let Some(min_amount) = policy.min_amount {
+            require!(
+                amount >= min_amount,
+                TokenTransferHookError::AmountBelowMinimum
+            );
+        }
+        if let Some(max_amount) = policy.max_amount {
+            require!(
+                amount <= max_amount,
+                TokenTransferHookError::AmountAboveMaximum
+            );
+        }
+        if let Some(max_balance) = policy.max_balance {
+            // the hook runs after token-2022 has credited the destination
+            require!(
+                self.destination_token.amount <= max_balance,
+                TokenTransferHookError::MaxBalanceExceeded
+            );
+        }
+        if policy.enforce_lockups {
+            self.check_lockup()?;
+        }
+        if let Some(threshold) = policy.travel_rule_threshold {
+            if amount > threshold {
+                self.check_travel_rule_memo()?;
+            }
+        }
+        if let Some(window) = policy.trading_window {
+            let hour = Clock::get()?.unix_timestamp / SECONDS_PER_HOUR % HOURS_PER_DAY;
+            let (start_hour, end_hour) = (window.start_hour as i64, window.end_hour as i64);
+            let is_open = if start_hour < end_hour {
+                hour >= start_hour && hour < end_hour
+            } else {
+                hour >= start_hour || hour < end_hour
+            };
+            require!(is_open, TokenTransferHookError::OutsideAllowedHours);
+        }
+        Ok(decision)
+    }
+
+    // the top-level instruction right before the transfer must be the travel-rule memo
+    fn check_travel_rule_memo(&self) -> Result<()> {
+        let instructions_sysvar = self.instructions_sysvar.to_account_info();
+        let current_index = load_current_index_checked(&instructions_sysvar)?;
+        require!(
+            current_index > 0,
+            TokenTransferHookError::TravelRuleMemoMissing
+        );
+        let memo = load_instruction_at_checked(current_index as usize - 1, &instructions_sysvar)?;
+        let hash = memo.data.strip_prefix(TRAVEL_RULE_MEMO_PREFIX);
+        require!(
+            memo.program_id == MEMO_PROGRAM_ID
+                && hash.is_some_and(|hash| hash.len() == TRAVEL_RULE_HASH_HEX_LEN
+                    && hash.iter().all(u8::is_ascii_hexdigit)),
+            TokenTransferHookError::TravelRuleMemoMissing
+        );
+        Ok(())
+    }
+
+    fn check_lockup(&self) -> Result<()> {
+        if self.source_lockup.owner != self.rwa_program.key {
+            return Ok(());
+        }
+        // Lockup stores the schedule right after the account discriminator
+        let data = self.source_lockup.try_borrow_data()?;
+        let schedule = LockupSchedule::deserialize(&mut &data[8..])?;
+        let locked = schedule.locked_at(Clock::get()?.unix_timestamp);
+        // the hook runs after token-2022 has debited the source
+        require!(
+            self.source_token.amount >= locked,
+            TokenTransferHookError::TokensLocked
+        );
+        Ok(())
+    }
+
+    fn record_transfer(&self, policy: &TransferPolicy, amount: u64) -> Result<()> {
+        if self.transfer_stats.data_is_empty() {
+            require!(
+                policy.velocity_limit.is_none(),
+                TokenTransferHookError::TransferStatsNotInitialized
+            );
+            return Ok(());
+        }
+        require_keys_eq!(
+            *self.transfer_stats.owner,
+            crate::ID,
+            TokenTransferHookError::TransferStatsNotInitialized
+        );
+
+        let mut data = self.transfer_stats.try_borrow_mut_data()?;
+        let mut stats = TransferStats::try_deserialize(&mut &data[..])?;
+        let clock = Clock::get()?;
+        stats.record(
+            amount,
+            clock.unix_timestamp,
+            clock.slot,
+            policy.velocity_limit,
+        )?;
+
+        stats.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+}
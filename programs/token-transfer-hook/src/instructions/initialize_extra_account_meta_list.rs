@@ -0,0 +1,228 @@
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, pubkey_data::PubkeyData, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{
+    check_transfer_hook_authority, error::TokenTransferHookError, CONSUMER_NFT_SEED, DENYLIST_SEED,
+    EVENT_AUTHORITY_SEED, EXTRA_ACCOUNT_METAS_SEED, LOCKUP_SEED, MINT_AUTHORITY_SEED,
+    RWA_PROGRAM_ID, TRANSFER_POLICY_SEED, TRANSFER_STATS_SEED,
+};
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// the mint's MintAuthority PDA, signing via CPI from rwa-tokenization
+    pub authority: Signer<'info>,
+
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        init,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+        space = ExtraAccountMetaList::size_of(
+            InitializeExtraAccountMetaList::extra_account_metas()?.len()
+        )?,
+        payer = payer
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Program id issue the certificate nft
+    #[account(address = RWA_PROGRAM_ID)]
+    pub rwa_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Define extra account metas to store on extra_account_meta_list account
+impl<'info> InitializeExtraAccountMetaList<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        let (mint_authority, _) = Pubkey::find_program_address(
+            &[MINT_AUTHORITY_SEED, self.mint.key().as_ref()],
+            &RWA_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            self.authority.key(),
+            mint_authority,
+            TokenTransferHookError::UnAuthorized
+        );
+        check_transfer_hook_authority(&self.mint.to_account_info(), &self.authority.key())?;
+
+        let extra_account_metas = InitializeExtraAccountMetaList::extra_account_metas()?;
+
+        // initialize ExtraAccountMetaList account with extra accounts
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut self.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+
+        Ok(())
+    }
+
+    // token-2022 resolves these on its 32KB heap and runs out of memory past 15 extra accounts,
+    // so per-mint settings belong in the transfer policy rather than in accounts of their own
+    pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+        let account_metas = vec![
+            // index 5: rwa-tokenization program
+            ExtraAccountMeta::new_with_pubkey(&RWA_PROGRAM_ID, false, false)?,
+            // index 6: consumer cert nft of the source owner
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[
+                    Seed::Literal {
+                        bytes: CONSUMER_NFT_SEED.to_vec(),
+                    },
+                    Seed::AccountKey { index: 1 },
+                    Seed::AccountData {
+                        account_index: 0,
+                        data_index: 32,
+                        length: 32,
+                    },
+                ],
+                false,
+                false,
+            )?,
+            // index 7: consumer cert nft of the destination owner
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[
+                    Seed::Literal {
+                        bytes: CONSUMER_NFT_SEED.to_vec(),
+                    },
+                    Seed::AccountKey { index: 1 },
+                    Seed::AccountData {
+                        account_index: 2,
+                        data_index: 32,
+                        length: 32,
+                    },
+                ],
+                false,
+                false,
+            )?,
+            // index 8: transfer policy of the mint
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: TRANSFER_POLICY_SEED.to_vec(),
+                    },
+                    Seed::AccountKey { index: 1 },
+                ],
+                false,
+                false,
+            )?,
+            // index 9: transfer stats of the source owner, updated on every transfer
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: TRANSFER_STATS_SEED.to_vec(),
+                    },
+                    Seed::AccountKey { index: 1 },
+                    Seed::AccountData {
+                        account_index: 0,
+                        data_index: 32,
+                        length: 32,
+                    },
+                ],
+                false,
+                true,
+            )?,
+            // index 10: destination owner, to look up the program owning it
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData {
+                    account_index: 2,
+                    data_index: 32,
+                },
+                false,
+                false,
+            )?,
+            // index 11: denylist entry of the source owner
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[
+                    Seed::Literal {
+                        bytes: DENYLIST_SEED.to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 0,
+                        data_index: 32,
+                        length: 32,
+                    },
+                ],
+                false,
+                false,
+            )?,
+            // index 12: denylist entry of the destination owner
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[
+                    Seed::Literal {
+                        bytes: DENYLIST_SEED.to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 2,
+                        data_index: 32,
+                        length: 32,
+                    },
+                ],
+                false,
+                false,
+            )?,
+            // index 13: consumer controller of the source cert nft
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[
+                    Seed::Literal {
+                        bytes: CONSUMER_NFT_SEED.to_vec(),
+                    },
+                    Seed::AccountKey { index: 6 },
+                ],
+                false,
+                false,
+            )?,
+            // index 14: consumer controller of the destination cert nft
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[
+                    Seed::Literal {
+                        bytes: CONSUMER_NFT_SEED.to_vec(),
+                    },
+                    Seed::AccountKey { index: 7 },
+                ],
+                false,
+                false,
+            )?,
+            // index 15: lockup of the source owner, not of the transfer authority (a delegate)
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                5,
+                &[
+                    Seed::Literal {
+                        bytes: LOCKUP_SEED.to_vec(),
+                    },
+                    Seed::AccountKey { index: 1 },
+                    Seed::AccountData {
+                        account_index: 0,
+                        data_index: 32,
+                        length: 32,
+                    },
+                ],
+                false,
+                false,
+            )?,
+            // index 16: instructions sysvar, to find the travel-rule memo
+            ExtraAccountMeta::new_with_pubkey(&sysvar::instructions::ID, false, false)?,
+            // index 17: event authority of the hook, signs the transfer event cpi
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal {
+                    bytes: EVENT_AUTHORITY_SEED.to_vec(),
+                }],
+                false,
+                false,
+            )?,
+            // index 18: the hook program itself, target of the transfer event cpi
+            ExtraAccountMeta::new_with_pubkey(&crate::ID, false, false)?,
+        ];
+        Ok(account_metas)
+    }
+}
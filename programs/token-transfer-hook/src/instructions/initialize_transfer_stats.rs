@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{TransferStats, TRANSFER_STATS_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeTransferStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: token account owner tracked by the stats, can be SystemAccount or PDA
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TransferStats::INIT_SPACE,
+        seeds = [TRANSFER_STATS_SEED, mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub transfer_stats: Box<Account<'info, TransferStats>>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeTransferStats<'info> {
+    pub fn handler(&mut self, bumps: &InitializeTransferStatsBumps) -> Result<()> {
+        self.transfer_stats.set_inner(TransferStats {
+            mint: self.mint.key(),
+            owner: self.owner.key(),
+            window_start: 0,
+            window_volume: 0,
+            last_transfer_slot: 0,
+            bump: bumps.transfer_stats,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+pub mod initialize_extra_account_meta_list;
+
+pub use initialize_extra_account_meta_list::*;
+
+pub mod execute;
+
+pub use execute::*;
+
+pub mod close_extra_account_meta_list;
+
+pub use close_extra_account_meta_list::*;
+
+pub mod set_transfer_policy;
+
+pub use set_transfer_policy::*;
+
+pub mod initialize_transfer_stats;
+
+pub use initialize_transfer_stats::*;
+
+pub mod set_allowed_account;
+
+pub use set_allowed_account::*;
+
+pub mod set_allowed_program_owner;
+
+pub use set_allowed_program_owner::*;
+
+pub mod set_jurisdiction_rules;
+
+pub use set_jurisdiction_rules::*;
+
+pub mod update_extra_account_meta_list;
+
+pub use update_extra_account_meta_list::*;
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{
+    check_transfer_hook_authority, error::TokenTransferHookError, TransferPolicy,
+    MAX_ALLOWED_ACCOUNTS, TRANSFER_POLICY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetAllowedAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the mint's transfer hook authority, usually a PDA signing via CPI
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TransferPolicy::INIT_SPACE,
+        seeds = [TRANSFER_POLICY_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_policy: Box<Account<'info, TransferPolicy>>,
+    #[account(token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAllowedAccount<'info> {
+    pub fn handler(&mut self, is_allowed: bool, bumps: &SetAllowedAccountBumps) -> Result<()> {
+        check_transfer_hook_authority(&self.mint.to_account_info(), &self.authority.key())?;
+
+        let token_account = self.token_account.key();
+        self.transfer_policy
+            .init_if_needed(self.mint.key(), bumps.transfer_policy);
+        let accounts = &mut self.transfer_policy.allowed_accounts;
+        if is_allowed {
+            if !accounts.contains(&token_account) {
+                require!(
+                    accounts.len() < MAX_ALLOWED_ACCOUNTS,
+                    TokenTransferHookError::TooManyAllowedAccounts
+                );
+                accounts.push(token_account);
+            }
+        } else {
+            accounts.retain(|account| account != &token_account);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{token_2022::spl_token_2022, token_interface::Mint};
+
+use crate::{
+    check_transfer_hook_authority, error::TokenTransferHookError, TransferPolicy,
+    MAX_ALLOWED_PROGRAM_OWNERS, TRANSFER_POLICY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetAllowedProgramOwner<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the mint's transfer hook authority, usually a PDA signing via CPI
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TransferPolicy::INIT_SPACE,
+        seeds = [TRANSFER_POLICY_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_policy: Box<Account<'info, TransferPolicy>>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAllowedProgramOwner<'info> {
+    pub fn handler(
+        &mut self,
+        program_id: Pubkey,
+        is_allowed: bool,
+        bumps: &SetAllowedProgramOwnerBumps,
+    ) -> Result<()> {
+        check_transfer_hook_authority(&self.mint.to_account_info(), &self.authority.key())?;
+        // wallets are system owned, allowing these would switch the consumer check off
+        require!(
+            program_id != system_program::ID && program_id != spl_token_2022::ID,
+            TokenTransferHookError::InvalidProgramOwner
+        );
+
+        self.transfer_policy
+            .init_if_needed(self.mint.key(), bumps.transfer_policy);
+        let programs = &mut self.transfer_policy.allowed_program_owners;
+        if is_allowed {
+            if !programs.contains(&program_id) {
+                require!(
+                    programs.len() < MAX_ALLOWED_PROGRAM_OWNERS,
+                    TokenTransferHookError::TooManyProgramOwners
+                );
+                programs.push(program_id);
+            }
+        } else {
+            programs.retain(|program| program != &program_id);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    check_transfer_hook_authority, error::TokenTransferHookError, JurisdictionRule,
+    JurisdictionRules, TransferPolicy, MAX_JURISDICTION_RULES, TRANSFER_POLICY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetJurisdictionRules<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the mint's transfer hook authority, usually a PDA signing via CPI
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TransferPolicy::INIT_SPACE,
+        seeds = [TRANSFER_POLICY_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_policy: Box<Account<'info, TransferPolicy>>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetJurisdictionRules<'info> {
+    pub fn handler(
+        &mut self,
+        default_allowed: bool,
+        rules: Vec<JurisdictionRule>,
+        bumps: &SetJurisdictionRulesBumps,
+    ) -> Result<()> {
+        check_transfer_hook_authority(&self.mint.to_account_info(), &self.authority.key())?;
+        require!(
+            rules.len() <= MAX_JURISDICTION_RULES,
+            TokenTransferHookError::TooManyJurisdictionRules
+        );
+        for (i, rule) in rules.iter().enumerate() {
+            require!(
+                rule.jurisdiction.is_valid()
+                    && !rules[..i]
+                        .iter()
+                        .any(|other| other.jurisdiction == rule.jurisdiction),
+                TokenTransferHookError::InvalidJurisdiction
+            );
+        }
+
+        // the whole matrix is replaced on every call
+        self.transfer_policy
+            .init_if_needed(self.mint.key(), bumps.transfer_policy);
+        self.transfer_policy.jurisdiction_rules = Some(JurisdictionRules {
+            default_allowed,
+            rules,
+        });
+        Ok(())
+    }
+}
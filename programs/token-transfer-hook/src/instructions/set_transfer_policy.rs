@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    check_transfer_hook_authority, error::TokenTransferHookError, TransferPolicy,
+    TransferPolicyParams, HOURS_PER_DAY, TRANSFER_POLICY_SEED,
+};
+
+#[derive(Accounts)]
+pub struct SetTransferPolicy<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the mint's transfer hook authority, usually a PDA signing via CPI
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TransferPolicy::INIT_SPACE,
+        seeds = [TRANSFER_POLICY_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub transfer_policy: Box<Account<'info, TransferPolicy>>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetTransferPolicy<'info> {
+    pub fn handler(
+        &mut self,
+        params: TransferPolicyParams,
+        bumps: &SetTransferPolicyBumps,
+    ) -> Result<()> {
+        check_transfer_hook_authority(&self.mint.to_account_info(), &self.authority.key())?;
+
+        if let (Some(min_amount), Some(max_amount)) = (params.min_amount, params.max_amount) {
+            require!(
+                min_amount <= max_amount,
+                TokenTransferHookError::InvalidPolicy
+            );
+        }
+        if let Some(velocity_limit) = params.velocity_limit {
+            require!(
+                velocity_limit.window_seconds > 0,
+                TokenTransferHookError::InvalidPolicy
+            );
+        }
+        if let Some(window) = params.trading_window {
+            require!(
+                (window.start_hour as i64) < HOURS_PER_DAY
+                    && (window.end_hour as i64) < HOURS_PER_DAY
+                    && window.start_hour != window.end_hour,
+                TokenTransferHookError::InvalidPolicy
+            );
+        }
+
+        // the allowlists and jurisdiction rules are kept, they have their own setters
+        let policy = &mut self.transfer_policy;
+        policy.init_if_needed(self.mint.key(), bumps.transfer_policy);
+        policy.require_source_cert = params.require_source_cert;
+        policy.require_destination_cert = params.require_destination_cert;
+        policy.min_amount = params.min_amount;
+        policy.max_amount = params.max_amount;
+        policy.trading_window = params.trading_window;
+        policy.enforce_lockups = params.enforce_lockups;
+        policy.max_balance = params.max_balance;
+        policy.velocity_limit = params.velocity_limit;
+        policy.travel_rule_threshold = params.travel_rule_threshold;
+        Ok(())
+    }
+}
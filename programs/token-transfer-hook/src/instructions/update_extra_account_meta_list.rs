@@ -0,0 +1,76 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{
+    check_transfer_hook_authority, InitializeExtraAccountMetaList, EXTRA_ACCOUNT_METAS_SEED,
+    RWA_PROGRAM_ID,
+};
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// the mint's transfer hook authority, usually a PDA signing via CPI
+    pub authority: Signer<'info>,
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: Program id issue the certificate nft
+    #[account(address = RWA_PROGRAM_ID)]
+    pub rwa_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Rewrite the extra account metas of an existing mint with the current list
+impl<'info> UpdateExtraAccountMetaList<'info> {
+    pub fn handler(&mut self) -> Result<()> {
+        check_transfer_hook_authority(&self.mint.to_account_info(), &self.authority.key())?;
+
+        let extra_account_metas = InitializeExtraAccountMetaList::extra_account_metas()?;
+        let new_size = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
+        let extra_account_meta_list = self.extra_account_meta_list.to_account_info();
+        let old_size = extra_account_meta_list.data_len();
+
+        // grow before writing, shrink after, so the TLV entry always fits the buffer
+        if new_size > old_size {
+            let lamports = Rent::get()?
+                .minimum_balance(new_size)
+                .saturating_sub(extra_account_meta_list.lamports());
+            if lamports > 0 {
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.payer.to_account_info(),
+                            to: extra_account_meta_list.clone(),
+                        },
+                    ),
+                    lamports,
+                )?;
+            }
+            extra_account_meta_list.realloc(new_size, false)?;
+        }
+
+        ExtraAccountMetaList::update::<ExecuteInstruction>(
+            &mut extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+
+        if new_size < old_size {
+            extra_account_meta_list.realloc(new_size, false)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+// reason a wallet is on the rwa-tokenization denylist, shared so both programs agree on the layout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DenyReason {
+    Sanctions,
+    Fraud,
+    CourtOrder,
+    Other,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_JURISDICTION_RULES;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum InvestorClass {
+    Retail,
+    Professional,
+    Institutional,
+}
+
+// stored on the rwa-tokenization ConsumerController of every consumer cert
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct Jurisdiction {
+    // ISO 3166-1 alpha-2, e.g. b"US"
+    pub country_code: [u8; 2],
+    pub investor_class: InvestorClass,
+}
+
+impl Jurisdiction {
+    pub fn is_valid(&self) -> bool {
+        self.country_code.iter().all(u8::is_ascii_uppercase)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct JurisdictionRule {
+    pub jurisdiction: Jurisdiction,
+    pub can_send: bool,
+    pub can_receive: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct JurisdictionRules {
+    // applies to jurisdictions without a rule and consumers issued before jurisdictions were stored
+    pub default_allowed: bool,
+    #[max_len(MAX_JURISDICTION_RULES)]
+    pub rules: Vec<JurisdictionRule>,
+}
+
+impl JurisdictionRules {
+    pub fn rule(&self, jurisdiction: Option<Jurisdiction>) -> Option<&JurisdictionRule> {
+        let jurisdiction = jurisdiction?;
+        self.rules
+            .iter()
+            .find(|rule| rule.jurisdiction == jurisdiction)
+    }
+
+    pub fn can_send(&self, jurisdiction: Option<Jurisdiction>) -> bool {
+        self.rule(jurisdiction)
+            .map(|rule| rule.can_send)
+            .unwrap_or(self.default_allowed)
+    }
+
+    pub fn can_receive(&self, jurisdiction: Option<Jurisdiction>) -> bool {
+        self.rule(jurisdiction)
+            .map(|rule| rule.can_receive)
+            .unwrap_or(self.default_allowed)
+    }
+}
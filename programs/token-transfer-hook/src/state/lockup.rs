@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+// stored first on the rwa-tokenization Lockup of a (mint, holder), read by the hook
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct LockupSchedule {
+    pub locked_amount: u64,
+    pub start_at: i64,
+    pub unlock_at: i64,
+    // unlock linearly between start_at and unlock_at instead of all at unlock_at
+    pub is_linear: bool,
+}
+
+impl LockupSchedule {
+    pub fn locked_at(&self, now: i64) -> u64 {
+        if now >= self.unlock_at {
+            return 0;
+        }
+        if !self.is_linear || now <= self.start_at {
+            return self.locked_amount;
+        }
+        let remaining = (self.unlock_at - now) as u128;
+        let duration = (self.unlock_at - self.start_at) as u128;
+        (self.locked_amount as u128 * remaining / duration) as u64
+    }
+}
@@ -0,0 +1,14 @@
+pub mod transfer_policy;
+pub use transfer_policy::*;
+
+pub mod transfer_stats;
+pub use transfer_stats::*;
+
+pub mod denylist;
+pub use denylist::*;
+
+pub mod jurisdiction;
+pub use jurisdiction::*;
+
+pub mod lockup;
+pub use lockup::*;
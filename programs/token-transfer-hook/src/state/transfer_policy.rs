@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{JurisdictionRules, MAX_ALLOWED_ACCOUNTS, MAX_ALLOWED_PROGRAM_OWNERS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct TradingWindow {
+    // UTC hours, the window wraps past midnight when start_hour > end_hour
+    pub start_hour: u8,
+    pub end_hour: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct VelocityLimit {
+    // maximum volume an owner may send within one window
+    pub window_seconds: i64,
+    pub max_volume: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferPolicyParams {
+    pub require_source_cert: bool,
+    pub require_destination_cert: bool,
+    pub min_amount: Option<u64>,
+    pub max_amount: Option<u64>,
+    pub trading_window: Option<TradingWindow>,
+    pub enforce_lockups: bool,
+    pub max_balance: Option<u64>,
+    pub velocity_limit: Option<VelocityLimit>,
+    pub travel_rule_threshold: Option<u64>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TransferPolicy {
+    pub mint: Pubkey,
+    pub require_source_cert: bool,
+    pub require_destination_cert: bool,
+    pub min_amount: Option<u64>,
+    pub max_amount: Option<u64>,
+    pub trading_window: Option<TradingWindow>,
+    pub enforce_lockups: bool,
+    pub max_balance: Option<u64>,
+    pub velocity_limit: Option<VelocityLimit>,
+    // transfers above this amount need a preceding travel-rule memo
+    pub travel_rule_threshold: Option<u64>,
+    // token accounts (e.g. an escrow or AMM vault) exempt from the consumer cert requirement
+    #[max_len(MAX_ALLOWED_ACCOUNTS)]
+    pub allowed_accounts: Vec<Pubkey>,
+    // programs whose accounts may own token accounts exempt from the consumer cert requirement
+    #[max_len(MAX_ALLOWED_PROGRAM_OWNERS)]
+    pub allowed_program_owners: Vec<Pubkey>,
+    // jurisdictions are not checked without rules
+    pub jurisdiction_rules: Option<JurisdictionRules>,
+    pub bump: u8,
+}
+
+impl TransferPolicy {
+    // mints without a policy account keep the original rule: both sides hold a consumer cert
+    pub fn permissioned(mint: Pubkey) -> Self {
+        Self {
+            mint,
+            require_source_cert: true,
+            require_destination_cert: true,
+            min_amount: None,
+            max_amount: None,
+            trading_window: None,
+            enforce_lockups: false,
+            max_balance: None,
+            velocity_limit: None,
+            travel_rule_threshold: None,
+            allowed_accounts: Vec::new(),
+            allowed_program_owners: Vec::new(),
+            jurisdiction_rules: None,
+            bump: 0,
+        }
+    }
+
+    // the allowlist and jurisdiction setters may create the account before set_transfer_policy
+    pub fn init_if_needed(&mut self, mint: Pubkey, bump: u8) {
+        if self.mint == Pubkey::default() {
+            *self = Self {
+                bump,
+                ..Self::permissioned(mint)
+            };
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::TokenTransferHookError, VelocityLimit, SECONDS_PER_DAY};
+
+#[account]
+#[derive(InitSpace)]
+pub struct TransferStats {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub window_start: i64,
+    pub window_volume: u64,
+    pub last_transfer_slot: u64,
+    pub bump: u8,
+}
+
+impl TransferStats {
+    // volume is tracked in daily windows when the policy has no velocity limit
+    pub fn record(
+        &mut self,
+        amount: u64,
+        now: i64,
+        slot: u64,
+        velocity_limit: Option<VelocityLimit>,
+    ) -> Result<()> {
+        let window_seconds = velocity_limit
+            .map(|limit| limit.window_seconds)
+            .unwrap_or(SECONDS_PER_DAY);
+        if now - self.window_start >= window_seconds {
+            self.window_start = now;
+            self.window_volume = 0;
+        }
+        self.window_volume = self
+            .window_volume
+            .checked_add(amount)
+            .ok_or(TokenTransferHookError::VelocityLimitExceeded)?;
+        if let Some(limit) = velocity_limit {
+            require!(
+                self.window_volume <= limit.max_volume,
+                TokenTransferHookError::VelocityLimitExceeded
+            );
+        }
+        self.last_transfer_slot = slot;
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::TransferFeeConfig,
+        transfer_hook::{TransferHook, TransferHookAccount},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+};
+use spl_transfer_hook_interface::error::TransferHookError;
+
+use crate::error::TokenTransferHookError;
+
+pub fn check_transfer_hook_authority(mint: &AccountInfo, authority: &Pubkey) -> Result<()> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let extension = mint_with_extension.get_extension::<TransferHook>()?;
+    require!(
+        Option::<Pubkey>::from(extension.authority) == Some(*authority),
+        TokenTransferHookError::UnAuthorized
+    );
+    Ok(())
+}
+
+pub fn check_token_account_is_transferring(account_data: &[u8]) -> Result<()> {
+    let token_account =
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(account_data)?;
+    let extension: &TransferHookAccount = token_account.get_extension::<TransferHookAccount>()?;
+    if bool::from(extension.transferring) {
+        Ok(())
+    } else {
+        Err(Into::<ProgramError>::into(
+            TransferHookError::ProgramCalledOutsideOfTransfer,
+        ))?
+    }
+}
+
+// a consumer certificate is a Token-2022 NFT mint, resolved from the rwa program PDA seeds
+pub fn is_consumer_cert(cert_mint: &AccountInfo) -> Result<bool> {
+    if cert_mint.owner != &spl_token_2022::ID || cert_mint.data_is_empty() {
+        return Ok(false);
+    }
+    let cert_data = cert_mint.try_borrow_data()?;
+    let cert = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&cert_data)?;
+    Ok(cert.base.supply == 1 && cert.base.decimals == 0)
+}
+
+// fee token-2022 withheld on the destination for this transfer, 0 without the fee extension
+pub fn get_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let Ok(transfer_fee_config) = mint_with_extension.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    Ok(transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .unwrap_or(0))
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::Pubkey;
+use token_transfer_hook::{
+    error::TokenTransferHookError, TransferStats, VelocityLimit, SECONDS_PER_DAY,
+};
+
+const START: i64 = 1_700_000_000;
+const LIMIT: VelocityLimit = VelocityLimit {
+    window_seconds: 3_600,
+    max_volume: 100,
+};
+
+fn stats() -> TransferStats {
+    TransferStats {
+        mint: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        window_start: START,
+        window_volume: 0,
+        last_transfer_slot: 0,
+        bump: 0,
+    }
+}
+
+#[test]
+fn volume_adds_up_within_the_window() {
+    let mut stats = stats();
+    stats.record(40, START, 1, Some(LIMIT)).unwrap();
+    stats.record(60, START + 3_599, 2, Some(LIMIT)).unwrap();
+
+    assert_eq!(stats.window_start, START);
+    assert_eq!(stats.window_volume, 100);
+    assert_eq!(stats.last_transfer_slot, 2);
+}
+
+#[test]
+fn volume_above_the_limit_is_rejected() {
+    let mut stats = stats();
+    stats.record(60, START, 1, Some(LIMIT)).unwrap();
+
+    assert_eq!(
+        stats.record(41, START + 1, 2, Some(LIMIT)).unwrap_err(),
+        TokenTransferHookError::VelocityLimitExceeded.into()
+    );
+}
+
+#[test]
+fn window_rolls_over_once_it_has_elapsed() {
+    let mut stats = stats();
+    stats.record(100, START, 1, Some(LIMIT)).unwrap();
+    stats
+        .record(100, START + LIMIT.window_seconds, 2, Some(LIMIT))
+        .unwrap();
+
+    assert_eq!(stats.window_start, START + LIMIT.window_seconds);
+    assert_eq!(stats.window_volume, 100);
+}
+
+#[test]
+fn volume_is_tracked_daily_without_a_limit() {
+    let mut stats = stats();
+    stats.record(u64::MAX / 2, START, 1, None).unwrap();
+    stats
+        .record(1, START + SECONDS_PER_DAY - 1, 2, None)
+        .unwrap();
+    assert_eq!(stats.window_volume, u64::MAX / 2 + 1);
+
+    stats.record(1, START + SECONDS_PER_DAY, 3, None).unwrap();
+    assert_eq!(stats.window_start, START + SECONDS_PER_DAY);
+    assert_eq!(stats.window_volume, 1);
+}
+
+#[test]
+fn volume_overflow_is_rejected() {
+    let mut stats = stats();
+    stats.record(u64::MAX, START, 1, None).unwrap();
+
+    assert_eq!(
+        stats.record(1, START + 1, 2, None).unwrap_err(),
+        TokenTransferHookError::VelocityLimitExceeded.into()
+    );
+}